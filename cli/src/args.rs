@@ -0,0 +1,267 @@
+//! Command line arguments of `tabler`.
+
+use tabler::{
+    errors::FormatterError,
+    settings::{Align, Border, BorderStyle, Renderer, Shrink},
+};
+
+use crate::input::Format;
+
+pub const USAGE: &str = "\
+Render csv, tsv, json-lines or whitespace-separated input as a table.
+
+Usage: tabler [OPTIONS] [FILE]...
+
+Reads stdin when no FILE, or when FILE is -, is given.
+
+Options:
+  -f, --format <FORMAT>      Input format: csv, tsv, jsonl or whitespace.
+                             Guessed from the file extension or the first line by default.
+  -r, --renderer <RENDERER>  Output format: normal, raw, markdown, html, latex, rst-grid,
+                             rst-simple, asciidoc, org, mediawiki or jira-wiki.
+                             [default: normal]
+  -b, --border <BORDER>      Border of the table: none, all, horizontal or vertical. [default: none]
+  -s, --border-style <STYLE> Characters of the border: default, ascii, single, double, rounded or heavy.
+  -c, --column-separator     Draw vertical lines between columns.
+  -a, --align <COL=ALIGN>    Align a column to left, right or center. COL is a 1-based index or a header, in any case.
+                             Could be repeated, or separated by commas, e.g. `-a 2=right,name=center`.
+  -H, --header               Treat the first record of each input as the header.
+                             Objects in json-lines input always have a header.
+  -w, --width <WIDTH>        Maximum width of each column. Longer content is cut with an ellipsis.
+  -m, --max-width <WIDTH>    Maximum width of the whole table. Columns are shrunk to fit.
+                             `auto` fits the terminal, unless the output is not a terminal, and `none` does not
+                             limit the width. [default: auto]
+      --shrink <STRATEGY>    How columns are shrunk: proportional or widest. [default: proportional]
+      --wrap                 Wrap longer content into multiple lines instead of cutting it.
+  -h, --help                 Print help.
+  -V, --version              Print version.
+";
+
+/// A column referred to by `--align`.
+pub enum Column {
+    /// 1-based index.
+    Index(usize),
+    Name(String),
+}
+
+pub struct Args {
+    pub format: Option<Format>,
+    pub renderer: Renderer,
+    pub border: Border,
+    pub column_separator: bool,
+    pub aligns: Vec<(Column, Align)>,
+    pub header: bool,
+    pub width: Option<usize>,
+    pub max_width: Option<usize>,
+    /// `--max-width auto`.
+    pub fit_terminal: bool,
+    pub shrink: Shrink,
+    pub wrap: bool,
+    pub files: Vec<String>,
+}
+
+pub enum Command {
+    Run(Args),
+    Help,
+    Version,
+}
+
+pub fn parse(mut argv: impl Iterator<Item = String>) -> Result<Command, FormatterError> {
+    let mut args = Args {
+        format: None,
+        renderer: Renderer::Normal,
+        border: Border::Default,
+        column_separator: false,
+        aligns: vec![],
+        header: false,
+        width: None,
+        max_width: None,
+        fit_terminal: true,
+        shrink: Shrink::Proportional,
+        wrap: false,
+        files: vec![],
+    };
+    while let Some(arg) = argv.next() {
+        if arg == "--" {
+            args.files.extend(argv.by_ref());
+            break;
+        }
+        if arg == "-" || !arg.starts_with('-') {
+            args.files.push(arg);
+            continue;
+        }
+        // Both `--flag value` and `--flag=value` are accepted.
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) if arg.starts_with("--") => (flag.to_string(), Some(value.to_string())),
+            _ => (arg, None),
+        };
+        let mut value = || {
+            inline
+                .clone()
+                .or_else(|| argv.next())
+                .ok_or_else(|| FormatterError::new(format!("Missing value for {}", flag)))
+        };
+        match flag.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-V" | "--version" => return Ok(Command::Version),
+            "-H" | "--header" => args.header = true,
+            "-c" | "--column-separator" => args.column_separator = true,
+            "--wrap" => args.wrap = true,
+            "-f" | "--format" => args.format = Some(Format::from_name(&value()?)?),
+            "-r" | "--renderer" => args.renderer = Renderer::native_from_name(&value()?)?,
+            "-b" | "--border" => args.border = parse_border(&value()?)?.native_with_style(args.border.get_style()),
+            "-s" | "--border-style" => {
+                args.border = args.border.native_with_style(BorderStyle::native_from_name(&value()?)?)
+            }
+            "-a" | "--align" => {
+                for spec in value()?.split(',').filter(|s| !s.is_empty()) {
+                    args.aligns.push(parse_align(spec)?);
+                }
+            }
+            "-w" | "--width" => args.width = Some(parse_width(&value()?)?),
+            "-m" | "--max-width" => {
+                let value = value()?;
+                (args.max_width, args.fit_terminal) = match value.to_ascii_lowercase().as_str() {
+                    "auto" => (None, true),
+                    "none" => (None, false),
+                    _ => (Some(parse_width(&value)?), false),
+                }
+            }
+            "--shrink" => {
+                let value = value()?;
+                args.shrink = match value.to_ascii_lowercase().as_str() {
+                    "proportional" => Shrink::Proportional,
+                    "widest" => Shrink::Widest,
+                    _ => return Err(FormatterError::new(format!("Invalid shrink strategy: {}", value))),
+                }
+            }
+            _ => return Err(FormatterError::new(format!("Unknown option: {}", flag))),
+        }
+    }
+    Ok(Command::Run(args))
+}
+
+fn parse_width(s: &str) -> Result<usize, FormatterError> {
+    match s.parse::<usize>() {
+        Ok(width) if width > 0 => Ok(width),
+        _ => Err(FormatterError::new(format!("Invalid width: {}", s))),
+    }
+}
+
+fn parse_border(s: &str) -> Result<Border, FormatterError> {
+    match s.to_ascii_lowercase().as_str() {
+        "none" => Ok(Border::Default),
+        "all" => Ok(Border::All),
+        "horizontal" => Ok(Border::Horizontal),
+        "vertical" => Ok(Border::Vertical),
+        _ => Err(FormatterError::new(format!("Invalid Border Name: {}", s))),
+    }
+}
+
+fn parse_align(spec: &str) -> Result<(Column, Align), FormatterError> {
+    let (column, align) = spec
+        .rsplit_once('=')
+        .ok_or_else(|| FormatterError::new(format!("Invalid alignment, expected COL=ALIGN: {}", spec)))?;
+    let column = match column.parse::<usize>() {
+        Ok(0) => return Err(FormatterError::new("Column index starts from 1".to_string())),
+        Ok(index) => Column::Index(index),
+        Err(_) => Column::Name(column.to_string()),
+    };
+    let align = match align.to_ascii_lowercase().as_str() {
+        "left" => Align::Left,
+        "right" => Align::Right,
+        "center" => Align::Center,
+        _ => return Err(FormatterError::new(format!("Invalid Align Name: {}", align))),
+    };
+    Ok((column, align))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(argv: &[&str]) -> Result<Args, FormatterError> {
+        match parse(argv.iter().map(|s| s.to_string()))? {
+            Command::Run(args) => Ok(args),
+            _ => panic!("expected a run of {:?}", argv),
+        }
+    }
+
+    fn error(argv: &[&str]) -> String {
+        match run(argv) {
+            Err(e) => e.to_string(),
+            Ok(_) => panic!("expected an error for {:?}", argv),
+        }
+    }
+
+    #[test]
+    fn flags_and_files() {
+        let args = run(&["-H", "--renderer=markdown", "-f", "tsv", "--wrap", "a.txt", "-", "--", "-c"]).unwrap();
+        assert!(args.header);
+        assert!(args.wrap);
+        assert!(!args.column_separator);
+        assert_eq!(args.renderer, Renderer::Markdown);
+        assert_eq!(args.format, Some(Format::Tsv));
+        assert_eq!(args.files, ["a.txt", "-", "-c"]);
+    }
+
+    #[test]
+    fn help_and_version() {
+        assert!(matches!(parse(["-c", "--help"].map(String::from).into_iter()), Ok(Command::Help)));
+        assert!(matches!(parse(["-V"].map(String::from).into_iter()), Ok(Command::Version)));
+    }
+
+    #[test]
+    fn aligns() {
+        let args = run(&["-a", "2=right,name=center", "--align=a=b=left"]).unwrap();
+        let aligns = args
+            .aligns
+            .iter()
+            .map(|(column, align)| match column {
+                Column::Index(index) => (index.to_string(), *align),
+                Column::Name(name) => (format!("'{}'", name), *align),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            aligns,
+            [
+                ("2".to_string(), Align::Right),
+                ("'name'".to_string(), Align::Center),
+                ("'a=b'".to_string(), Align::Left)
+            ]
+        );
+        assert_eq!(error(&["-a", "0=left"]), "Column index starts from 1");
+        assert_eq!(error(&["-a", "1=top"]), "Invalid Align Name: top");
+        assert_eq!(error(&["-a", "right"]), "Invalid alignment, expected COL=ALIGN: right");
+    }
+
+    #[test]
+    fn widths() {
+        let args = run(&[]).unwrap();
+        assert_eq!((args.width, args.max_width, args.fit_terminal), (None, None, true));
+        let args = run(&["-w", "8", "--max-width", "40"]).unwrap();
+        assert_eq!((args.width, args.max_width, args.fit_terminal), (Some(8), Some(40), false));
+        let args = run(&["-m", "none"]).unwrap();
+        assert_eq!((args.max_width, args.fit_terminal), (None, false));
+        let args = run(&["-m", "none", "-m", "AUTO"]).unwrap();
+        assert_eq!((args.max_width, args.fit_terminal), (None, true));
+        assert_eq!(error(&["-w", "0"]), "Invalid width: 0");
+        assert_eq!(error(&["-m", "wide"]), "Invalid width: wide");
+    }
+
+    #[test]
+    fn border_and_shrink() {
+        let args = run(&["-s", "ascii", "-b", "all", "--shrink", "widest"]).unwrap();
+        assert_eq!(args.border, Border::All.native_with_style(BorderStyle::native_from_name("ascii").unwrap()));
+        assert_eq!(args.shrink, Shrink::Widest);
+        assert_eq!(error(&["--shrink", "evenly"]), "Invalid shrink strategy: evenly");
+        assert_eq!(error(&["-b", "outer"]), "Invalid Border Name: outer");
+    }
+
+    #[test]
+    fn invalid_options() {
+        assert_eq!(error(&["--colour"]), "Unknown option: --colour");
+        assert_eq!(error(&["-x=1"]), "Unknown option: -x=1");
+        assert_eq!(error(&["a.csv", "--renderer"]), "Missing value for --renderer");
+    }
+}
@@ -0,0 +1,174 @@
+//! Parse the input of `tabler` into records.
+
+use std::path::Path;
+
+use serde_json::Value;
+use tabler::{errors::FormatterError, load};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Csv,
+    Tsv,
+    /// One json object or array per line. The keys of objects become the header.
+    JsonLines,
+    /// Fields separated by any whitespace, like the output of `kubectl get` or `ps`.
+    Whitespace,
+}
+
+impl Format {
+    pub fn from_name(s: &str) -> Result<Format, FormatterError> {
+        match s.to_ascii_lowercase().as_str() {
+            "csv" => Ok(Self::Csv),
+            "tsv" => Ok(Self::Tsv),
+            "jsonl" | "ndjson" => Ok(Self::JsonLines),
+            "whitespace" => Ok(Self::Whitespace),
+            _ => Err(FormatterError::new(format!("Invalid Format Name: {}", s))),
+        }
+    }
+
+    pub fn from_path(path: &str) -> Option<Format> {
+        let ext = Path::new(path).extension()?.to_str()?.to_ascii_lowercase();
+        match ext.as_str() {
+            "csv" => Some(Self::Csv),
+            "tsv" | "tab" => Some(Self::Tsv),
+            "jsonl" | "ndjson" => Some(Self::JsonLines),
+            _ => None,
+        }
+    }
+
+    /// Guess the format from the first non-empty line.
+    pub fn detect(text: &str) -> Format {
+        let line = text.lines().map(str::trim).find(|l| !l.is_empty()).unwrap_or_default();
+        if line.starts_with('{') || line.starts_with('[') {
+            Self::JsonLines
+        } else if line.contains('\t') {
+            Self::Tsv
+        } else if line.contains(',') {
+            Self::Csv
+        } else {
+            Self::Whitespace
+        }
+    }
+}
+
+pub struct Records {
+    /// Header given by the input itself, i.e. the keys of json objects.
+    pub header: Option<Vec<String>>,
+    pub rows: Vec<Vec<String>>,
+}
+
+pub fn parse(text: &str, format: Format) -> Result<Records, FormatterError> {
+    let rows = match format {
+        Format::Csv => load::csv::parse(text, ',')?,
+        Format::Tsv => load::csv::parse(text, '\t')?,
+        Format::JsonLines => return parse_json_lines(text),
+        Format::Whitespace => text
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| l.split_whitespace().map(str::to_string).collect())
+            .collect(),
+    };
+    Ok(Records { header: None, rows })
+}
+
+fn parse_json_lines(text: &str) -> Result<Records, FormatterError> {
+    let mut keys: Vec<String> = vec![];
+    let mut objects = vec![];
+    let mut rows = vec![];
+    for (index, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let value: Value = serde_json::from_str(line)
+            .map_err(|e| FormatterError::new(format!("Invalid json on line {}: {}", index + 1, e)))?;
+        match value {
+            Value::Object(map) => {
+                for key in map.keys() {
+                    if !keys.contains(key) {
+                        keys.push(key.clone());
+                    }
+                }
+                objects.push(map);
+            }
+            Value::Array(values) => rows.push(values.iter().map(to_text).collect()),
+            value => rows.push(vec![to_text(&value)]),
+        }
+        if !objects.is_empty() && !rows.is_empty() {
+            return Err(FormatterError::new(format!(
+                "Invalid json lines: line {} mixes objects with other values",
+                index + 1
+            )));
+        }
+    }
+    if objects.is_empty() {
+        return Ok(Records { header: None, rows });
+    }
+    let rows = objects
+        .iter()
+        .map(|map| keys.iter().map(|k| map.get(k).map(to_text).unwrap_or_default()).collect())
+        .collect();
+    Ok(Records { header: Some(keys), rows })
+}
+
+/// Strings are shown without quotes, and `null` as an empty cell.
+fn to_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        value => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(text: &str) -> String {
+        match parse(text, Format::JsonLines) {
+            Err(e) => e.to_string(),
+            Ok(_) => panic!("expected an error for {:?}", text),
+        }
+    }
+
+    #[test]
+    fn detect() {
+        assert_eq!(Format::detect("a,b\n1,2"), Format::Csv);
+        assert_eq!(Format::detect("a\tb,c\n1\t2"), Format::Tsv);
+        assert_eq!(Format::detect("\n  {\"a\": 1}\n"), Format::JsonLines);
+        assert_eq!(Format::detect("[1, 2]"), Format::JsonLines);
+        assert_eq!(Format::detect("NAME   READY   STATUS\nweb-1  1/1     Running"), Format::Whitespace);
+        assert_eq!(Format::detect(""), Format::Whitespace);
+    }
+
+    #[test]
+    fn from_name_and_path() {
+        assert_eq!(Format::from_name("CSV").unwrap(), Format::Csv);
+        assert_eq!(Format::from_name("ndjson").unwrap(), Format::JsonLines);
+        assert_eq!(Format::from_name("xml").unwrap_err().to_string(), "Invalid Format Name: xml");
+        assert_eq!(Format::from_path("data/pods.TSV"), Some(Format::Tsv));
+        assert_eq!(Format::from_path("log.jsonl"), Some(Format::JsonLines));
+        assert_eq!(Format::from_path("notes.txt"), None);
+        assert_eq!(Format::from_path("-"), None);
+    }
+
+    #[test]
+    fn whitespace() {
+        let records = parse("NAME   RESTARTS\n\nweb-1  0\n  db   12  \n", Format::Whitespace).unwrap();
+        assert_eq!(records.header, None);
+        assert_eq!(records.rows, [["NAME", "RESTARTS"], ["web-1", "0"], ["db", "12"]]);
+    }
+
+    #[test]
+    fn json_lines() {
+        let records = parse("{\"a\": \"x\", \"b\": 1}\n\n{\"c\": null, \"a\": [1]}\n", Format::JsonLines).unwrap();
+        assert_eq!(records.header.unwrap(), ["a", "b", "c"]);
+        assert_eq!(records.rows, [["x", "1", ""], ["[1]", "", ""]]);
+
+        let records = parse("[\"x\", true]\n3", Format::JsonLines).unwrap();
+        assert_eq!(records.header, None);
+        assert_eq!(records.rows, [vec!["x", "true"], vec!["3"]]);
+
+        assert_eq!(error("{\"a\": 1}\n[1]"), "Invalid json lines: line 2 mixes objects with other values");
+        assert!(error("{\"a\": 1").starts_with("Invalid json on line 1"));
+    }
+}
@@ -0,0 +1,160 @@
+//! `tabler` renders csv, tsv, json-lines or whitespace-separated input as a table, see `tabler --help`.
+
+use std::{
+    env, fs,
+    io::{self, Read, Write},
+    process,
+};
+
+use itertools::Itertools;
+use tabler::{
+    errors::FormatterError,
+    settings::Overflow,
+    table::{self, Cell, Content, Table},
+};
+
+use args::{Args, Column, Command};
+use input::Format;
+
+mod args;
+mod input;
+
+fn main() {
+    let command = match args::parse(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("tabler: {}", e);
+            eprintln!("Try 'tabler --help' for more information.");
+            process::exit(2);
+        }
+    };
+    match command {
+        Command::Help => print!("{}", args::USAGE),
+        Command::Version => println!("tabler {}", env!("CARGO_PKG_VERSION")),
+        Command::Run(args) => {
+            if let Err(e) = run(&args) {
+                eprintln!("tabler: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+}
+
+fn run(args: &Args) -> Result<(), FormatterError> {
+    let stdin = ["-".to_string()];
+    let files = if args.files.is_empty() { &stdin[..] } else { &args.files[..] };
+    let mut header = None;
+    let mut rows = vec![];
+    for file in files {
+        let text = read(file)?;
+        let format = args
+            .format
+            .or_else(|| Format::from_path(file))
+            .unwrap_or_else(|| Format::detect(&text));
+        let mut records = input::parse(&text, format)?;
+        if records.header.is_none() && args.header && !records.rows.is_empty() {
+            records.header = Some(records.rows.remove(0));
+        }
+        header = header.or(records.header);
+        rows.append(&mut records.rows);
+    }
+
+    let table = build(args, header, rows)?;
+    let mut out = vec![];
+    table.native_rendered_by(args.renderer, &mut out)?;
+    match io::stdout().write_all(&out) {
+        // Output piped into `head` and the like.
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+        r => r.map_err(|e| FormatterError::new(format!("Failed to write output: {}", e))),
+    }
+}
+
+fn read(file: &str) -> Result<String, FormatterError> {
+    if file == "-" {
+        let mut text = String::new();
+        io::stdin()
+            .read_to_string(&mut text)
+            .map_err(|e| FormatterError::new(format!("Failed to read stdin: {}", e)))?;
+        Ok(text)
+    } else {
+        fs::read_to_string(file).map_err(|e| FormatterError::new(format!("Failed to read {}: {}", file, e)))
+    }
+}
+
+fn build(args: &Args, header: Option<Vec<String>>, rows: Vec<Vec<String>>) -> Result<Table, FormatterError> {
+    let w = header.iter().chain(rows.iter()).map(|r| r.len()).max().unwrap_or(0);
+    let mut columns = vec![
+        table::Column::default()
+            .native_with_max_width(args.width)
+            .native_with_overflow(args.wrap.then_some(Overflow::Wrap));
+        w
+    ];
+    for (column, align) in &args.aligns {
+        let index = match column {
+            Column::Index(index) if *index <= w => index - 1,
+            Column::Index(index) => {
+                return Err(FormatterError::new(format!("Column {} is out of the table of {} columns", index, w)))
+            }
+            Column::Name(name) => header
+                .as_ref()
+                .and_then(|h| h.iter().position(|s| s == name).or_else(|| h.iter().position(|s| s.eq_ignore_ascii_case(name))))
+                .ok_or_else(|| FormatterError::new(format!("No column named {}", name)))?,
+        };
+        columns[index] = columns[index].clone().native_with_align(Some(*align));
+    }
+
+    let to_cells = |record: Vec<String>| {
+        let len = record.len();
+        record
+            .into_iter()
+            .map(|s| Cell::default().native_with_content(Content::text(s)))
+            .chain(std::iter::repeat_n(Cell::default().native_with_content(Content::Empty), w - len))
+            .collect_vec()
+    };
+    let rows = rows.into_iter().map(to_cells).collect_vec();
+    let table = match header {
+        Some(header) => Table::native_create(to_cells(header), rows, true),
+        None => Table::new(rows),
+    };
+    Ok(table
+        .native_with_columns(columns)
+        .native_with_border(args.border)
+        .native_with_column_separator(args.column_separator)
+        .native_with_max_width(args.max_width)
+        .native_with_fit_terminal(args.fit_terminal)
+        .native_with_shrink(args.shrink.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use tabler::settings::Renderer;
+
+    use super::*;
+
+    fn render(argv: &[&str], header: &[&str], rows: &[&[&str]]) -> Result<String, FormatterError> {
+        let args = match args::parse(argv.iter().map(|s| s.to_string()))? {
+            Command::Run(args) => args,
+            _ => unreachable!(),
+        };
+        let to_strings = |r: &[&str]| r.iter().map(|s| s.to_string()).collect_vec();
+        let table = build(&args, Some(to_strings(header)), rows.iter().map(|r| to_strings(r)).collect())?;
+        let mut out = vec![];
+        table.native_rendered_by(Renderer::Markdown, &mut out)?;
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn align_by_name() {
+        let rows: &[&[&str]] = &[&["web-1", "0"]];
+        let expected = "|NAME|RESTARTS|\n|:--|--:|\n|web-1|0|\n";
+        assert_eq!(render(&["-a", "RESTARTS=right"], &["NAME", "RESTARTS"], rows).unwrap(), expected);
+        assert_eq!(render(&["-a", "restarts=right"], &["NAME", "RESTARTS"], rows).unwrap(), expected);
+        // An exact match wins over one in another case.
+        let expected = "|a|A|\n|--:|:--|\n|1|2|\n";
+        assert_eq!(render(&["-a", "a=right"], &["a", "A"], &[&["1", "2"]]).unwrap(), expected);
+        let err = render(&["-a", "age=right"], &["NAME", "RESTARTS"], rows).unwrap_err();
+        assert_eq!(err.to_string(), "No column named age");
+        let err = render(&["-a", "3=right"], &["NAME", "RESTARTS"], rows).unwrap_err();
+        assert_eq!(err.to_string(), "Column 3 is out of the table of 2 columns");
+    }
+}
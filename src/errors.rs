@@ -1,4 +1,6 @@
+#[cfg(feature = "python")]
 use pyo3::{prelude::*, exceptions};
+use std::io;
 
 #[derive(Debug)]
 pub struct FormatterError {
@@ -19,6 +21,63 @@ impl std::fmt::Display for FormatterError {
     }
 }
 
+/// Errors of rendering a table.
+#[derive(Debug)]
+pub enum TableError {
+    InvalidTableSize {
+        row: usize,
+        expected: usize,
+        actual: usize,
+    },
+    MergeOutOfTable {
+        x: usize,
+        y: usize,
+        merge: usize,
+        max: usize,
+    },
+    MergeOverwrite {
+        x: usize,
+        y: usize,
+    },
+    /// A `row_span` crossing the header rule of a reStructuredText grid table.
+    SpanOverHeader {
+        x: usize,
+        y: usize,
+    },
+    WriteError(io::Error),
+}
+
+impl std::error::Error for TableError {}
+
+impl std::fmt::Display for TableError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidTableSize { row, expected, actual } => {
+                write!(f, "Invalid table size - row {row}: find {actual} cells, expect {expected}")
+            }
+            Self::MergeOutOfTable { x, y, merge, max } => {
+                write!(f, "Cell Merge out of table - ({x} {y}): merging {merge} cells, maximum {max} cells")
+            }
+            Self::MergeOverwrite { x, y } => write!(f, "Merge overwriting cells: ({x} {y})"),
+            Self::SpanOverHeader { x, y } => write!(f, "Row span crossing the header - ({x} {y})"),
+            Self::WriteError(err) => write!(f, "Write to buffer failed: {err}"),
+        }
+    }
+}
+
+impl std::convert::From<io::Error> for TableError {
+    fn from(err: io::Error) -> TableError {
+        TableError::WriteError(err)
+    }
+}
+
+impl std::convert::From<TableError> for FormatterError {
+    fn from(err: TableError) -> FormatterError {
+        FormatterError::new(err.to_string())
+    }
+}
+
+#[cfg(feature = "python")]
 impl std::convert::From<FormatterError> for PyErr {
     fn from(err: FormatterError) -> PyErr {
         exceptions::PyValueError::new_err(err.to_string())
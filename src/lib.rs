@@ -1,12 +1,24 @@
+//! Format plain-text tables, from both Rust and Python.
+//!
+//! The Python bindings are built with the default `python` feature.
+//! To use the lib from Rust only, disable the default features, and use the `native_*` methods of [table::Table] and [table::Cell].
+
+// `#[pymethods]` of pyo3 0.19 expands to non-local impls.
+#![allow(non_local_definitions)]
+
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
 
-mod settings;
-mod errors;
-mod table;
+pub mod errors;
+pub mod load;
+mod render;
+pub mod settings;
+pub mod table;
 
+#[cfg(feature = "python")]
 #[pymodule]
 fn tabler(py: Python, m: &PyModule) -> PyResult<()> {
     settings::regist_classes(py, m)?;
     table::regist_table(py, m)?;
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,138 @@
+//! Parse csv text, following RFC 4180.
+//!
+//! Quoted fields may contain delimiters, doubled quotes and line breaks. Empty lines are skipped,
+//! unless every record has a single field, where they are records with an empty field.
+//! Empty lines at the end of the text are always skipped.
+
+use crate::errors::FormatterError;
+
+pub fn parse(text: &str, delimiter: char) -> Result<Vec<Vec<String>>, FormatterError> {
+    // Empty lines are records without fields, until the number of columns is known.
+    let mut records: Vec<Vec<String>> = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    // Whether the current field is quoted, and whether the cursor is still inside the quotes.
+    let mut quoted = false;
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut chars = text.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if in_quotes {
+            match ch {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => {
+                    if ch == '\n' {
+                        line += 1;
+                    }
+                    field.push(ch)
+                }
+            }
+            continue;
+        }
+        match ch {
+            '"' if field.is_empty() && !quoted => {
+                quoted = true;
+                in_quotes = true;
+            }
+            '"' => {
+                return Err(FormatterError::new(format!(
+                    "Invalid csv: unexpected quote on line {line}"
+                )))
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' | '\r' => {
+                if quoted || !field.is_empty() || !record.is_empty() {
+                    record.push(std::mem::take(&mut field));
+                }
+                records.push(std::mem::take(&mut record));
+                quoted = false;
+                line += 1;
+            }
+            _ if ch == delimiter => {
+                record.push(std::mem::take(&mut field));
+                quoted = false;
+            }
+            _ if quoted => {
+                return Err(FormatterError::new(format!(
+                    "Invalid csv: unexpected character after a quoted field on line {line}"
+                )))
+            }
+            _ => field.push(ch),
+        }
+    }
+    if in_quotes {
+        return Err(FormatterError::new(format!(
+            "Invalid csv: unterminated quoted field on line {line}"
+        )));
+    }
+    if quoted || !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    while records.last().is_some_and(|r| r.is_empty()) {
+        records.pop();
+    }
+    if records.iter().all(|r| r.len() <= 1) {
+        for r in records.iter_mut().filter(|r| r.is_empty()) {
+            r.push(String::new());
+        }
+    } else {
+        records.retain(|r| !r.is_empty());
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsed(text: &str) -> Vec<Vec<String>> {
+        parse(text, ',').unwrap()
+    }
+
+    fn records(v: &[&[&str]]) -> Vec<Vec<String>> {
+        v.iter().map(|r| r.iter().map(|f| f.to_string()).collect()).collect()
+    }
+
+    #[test]
+    fn quoted_delimiters() {
+        assert_eq!(parsed("\"a,b\",c\n"), records(&[&["a,b", "c"]]));
+        assert_eq!(parse("\"a\tb\"\tc", '\t').unwrap(), records(&[&["a\tb", "c"]]));
+    }
+
+    #[test]
+    fn escaped_quotes() {
+        assert_eq!(parsed("\"say \"\"hi\"\"\",\"\"\"\"\n"), records(&[&["say \"hi\"", "\""]]));
+        assert!(parse("a\"b\",c", ',').is_err());
+        assert!(parse("\"a\"b,c", ',').is_err());
+        assert!(parse("\"a,b", ',').is_err());
+    }
+
+    #[test]
+    fn crlf() {
+        assert_eq!(parsed("a,b\r\nc,d\r\n"), records(&[&["a", "b"], &["c", "d"]]));
+        assert_eq!(parsed("a,b\rc,d"), records(&[&["a", "b"], &["c", "d"]]));
+    }
+
+    #[test]
+    fn embedded_newlines() {
+        assert_eq!(
+            parsed("\"a\nb\",\"c\r\nd\"\ne,f"),
+            records(&[&["a\nb", "c\r\nd"], &["e", "f"]])
+        );
+    }
+
+    #[test]
+    fn empty_lines() {
+        assert_eq!(parsed("a,b\n\nc,d\n\n"), records(&[&["a", "b"], &["c", "d"]]));
+        assert_eq!(parsed("a,\n,\n"), records(&[&["a", ""], &["", ""]]));
+        // A record of a single empty field is an empty line.
+        assert_eq!(parsed("a\n\nb\r\n\r\nc\n\n"), records(&[&["a"], &[""], &["b"], &[""], &["c"]]));
+        assert_eq!(parsed("a\n\"\"\n"), records(&[&["a"], &[""]]));
+        assert_eq!(parsed("\n\n"), records(&[]));
+    }
+}
@@ -0,0 +1,292 @@
+//! Load a table exported by `Table.to_json`.
+//!
+//! The schema is documented in the "JSON" section of the README.
+
+use serde_json::{Map, Value};
+
+use crate::{
+    errors::FormatterError,
+    render::json::VERSION,
+    settings::{Align, Border, BorderStyle, Formatter, MarkdownMerge, Overflow, Padding, Shrink, VAlign},
+    table::{Cell, Column, Content, ContentKind, Table},
+};
+
+pub fn parse(text: &str) -> Result<Table, FormatterError> {
+    let value: Value = serde_json::from_str(text)
+        .map_err(|e| FormatterError::new(format!("Invalid json: {e}")))?;
+    from_value(&value)
+}
+
+pub fn from_value(value: &Value) -> Result<Table, FormatterError> {
+    let object = value
+        .as_object()
+        .ok_or_else(|| invalid("table", value))?;
+    if let Some(version) = object.get("version") {
+        if version.as_u64() != Some(VERSION) {
+            return Err(FormatterError::new(format!(
+                "Unsupported table version: {version}"
+            )));
+        }
+    }
+    let mut rows = Vec::new();
+    for row in array(object, "table")?.unwrap_or(&Vec::new()) {
+        let row = row.as_array().ok_or_else(|| invalid("row", row))?;
+        rows.push(row.iter().map(cell_from_value).collect::<Result<Vec<_>, _>>()?);
+    }
+    let mut table = Table::new(rows);
+    if let Some(columns) = array(object, "columns")? {
+        table.native_set_columns(columns.iter().map(column_from_value).collect::<Result<Vec<_>, _>>()?);
+    }
+    if let Some(border) = object.get("border") {
+        let border_object = border.as_object().ok_or_else(|| invalid("border", border))?;
+        let side = |key| -> Result<bool, FormatterError> {
+            match border_object.get(key) {
+                Some(v) => v.as_bool().ok_or_else(|| invalid(key, v)),
+                None => Ok(false),
+            }
+        };
+        let style = match border_object.get("style") {
+            Some(v) => BorderStyle::from_json(v)?,
+            None => BorderStyle::Default,
+        };
+        table.native_set_border(
+            Border::new(side("left")?, side("right")?, side("top")?, side("bottom")?).native_with_style(style),
+        );
+    }
+    if let Some(separator) = object.get("column_separator") {
+        table.native_set_column_separator(
+            separator
+                .as_bool()
+                .ok_or_else(|| invalid("column_separator", separator))?,
+        );
+    }
+    if let Some(v) = object.get("max_width") {
+        table.native_set_max_width(optional_usize("max_width", v)?);
+    }
+    if let Some(v) = object.get("shrink") {
+        table.native_set_shrink(Shrink::from_json(v)?);
+    }
+    if let Some(v) = object.get("fit_terminal") {
+        table.native_set_fit_terminal(v.as_bool().ok_or_else(|| invalid("fit_terminal", v))?);
+    }
+    if let Some(v) = object.get("markdown_merge") {
+        let name = v.as_str().ok_or_else(|| invalid("markdown_merge", v))?;
+        table.native_set_markdown_merge(MarkdownMerge::native_from_name(name)?);
+    }
+    Ok(table)
+}
+
+fn cell_from_value(value: &Value) -> Result<Cell, FormatterError> {
+    let object = value.as_object().ok_or_else(|| invalid("cell", value))?;
+    // `null` is the same as a missing field.
+    let get = |key| object.get(key).filter(|v| !v.is_null());
+    let content = object
+        .get("content")
+        .ok_or_else(|| FormatterError::new(format!("Missing content: {value}")))?;
+    let content = match content {
+        Value::String(s) if s == "splitter" => Content::Splitter,
+        Value::String(s) if s == "empty" => Content::Empty,
+        Value::Object(o) => match o.get("text") {
+            Some(Value::String(text)) => Content::new(ContentKind::Text(text.clone())),
+            _ => Err(invalid("content", content))?,
+        },
+        _ => Err(invalid("content", content))?,
+    };
+    let mut cell = Cell::default().native_with_content(content);
+    if let Some(v) = get("overflow") {
+        cell = cell.native_with_overflow(overflow(v)?);
+    }
+    if let Some(v) = object.get("width") {
+        cell = cell.native_with_width(optional_usize("width", v)?);
+    }
+    if let Some(v) = get("align") {
+        cell = cell.native_with_align(align(v)?);
+    }
+    if let Some(v) = object.get("valign") {
+        cell = cell.native_with_valign(match v.as_str() {
+            Some("top") => VAlign::Top,
+            Some("middle") => VAlign::Middle,
+            Some("bottom") => VAlign::Bottom,
+            _ => Err(invalid("valign", v))?,
+        });
+    }
+    if let Some(v) = get("padding") {
+        cell = cell.native_with_padding(padding(v)?);
+    }
+    if let Some(v) = object.get("merge") {
+        cell = cell.native_with_merge(optional_usize("merge", v)?);
+    }
+    if let Some(v) = object.get("row_span") {
+        cell = cell.native_with_row_span(optional_usize("row_span", v)?);
+    }
+    if let Some(formatter) = array(object, "formatter")? {
+        cell = cell.native_with_formatter(
+            formatter
+                .iter()
+                .map(Formatter::from_json)
+                .collect::<Result<Vec<_>, _>>()?,
+        );
+    }
+    Ok(cell)
+}
+
+fn column_from_value(value: &Value) -> Result<Column, FormatterError> {
+    let object = value.as_object().ok_or_else(|| invalid("column", value))?;
+    // `null` is the same as a missing field.
+    let get = |key| object.get(key).filter(|v| !v.is_null());
+    let mut column = Column::default();
+    if let Some(v) = get("align") {
+        column = column.native_with_align(Some(align(v)?));
+    }
+    if let Some(v) = get("width") {
+        column = column.native_with_width(optional_usize("width", v)?);
+    }
+    if let Some(v) = get("min_width") {
+        column = column.native_with_min_width(optional_usize("min_width", v)?);
+    }
+    if let Some(v) = get("max_width") {
+        column = column.native_with_max_width(optional_usize("max_width", v)?);
+    }
+    if let Some(v) = get("overflow") {
+        column = column.native_with_overflow(Some(overflow(v)?));
+    }
+    if let Some(v) = get("padding") {
+        column = column.native_with_padding(Some(padding(v)?));
+    }
+    if let Some(formatter) = array(object, "formatter")? {
+        column = column.native_with_formatter(
+            formatter
+                .iter()
+                .map(Formatter::from_json)
+                .collect::<Result<Vec<_>, _>>()?,
+        );
+    }
+    if let Some(v) = get("header") {
+        column = column.native_with_header(Some(v.as_str().ok_or_else(|| invalid("header", v))?.to_string()));
+    }
+    Ok(column)
+}
+
+fn overflow(value: &Value) -> Result<Overflow, FormatterError> {
+    match value.as_str() {
+        Some("ellipsis") => Ok(Overflow::Ellipse),
+        Some("hidden") => Ok(Overflow::Hide),
+        Some("wrap") => Ok(Overflow::Wrap),
+        Some("wrap_char") => Ok(Overflow::WrapChar),
+        _ => Err(invalid("overflow", value)),
+    }
+}
+
+fn align(value: &Value) -> Result<Align, FormatterError> {
+    match value.as_str() {
+        Some("left") => Ok(Align::Left),
+        Some("center") => Ok(Align::Center),
+        Some("right") => Ok(Align::Right),
+        _ => Err(invalid("align", value)),
+    }
+}
+
+fn padding(value: &Value) -> Result<Padding, FormatterError> {
+    let padding = value.as_object().ok_or_else(|| invalid("padding", value))?;
+    let side = |key| -> Result<usize, FormatterError> {
+        match padding.get(key) {
+            Some(p) => Ok(optional_usize(key, p)?.unwrap_or(0)),
+            None => Ok(0),
+        }
+    };
+    Ok(Padding::new(side("left")?, side("right")?))
+}
+
+fn array<'a>(
+    object: &'a Map<String, Value>,
+    key: &str,
+) -> Result<Option<&'a Vec<Value>>, FormatterError> {
+    match object.get(key) {
+        Some(v) => v.as_array().map(Some).ok_or_else(|| invalid(key, v)),
+        None => Ok(None),
+    }
+}
+
+fn optional_usize(key: &str, value: &Value) -> Result<Option<usize>, FormatterError> {
+    match value {
+        Value::Null => Ok(None),
+        _ => value
+            .as_u64()
+            .and_then(|v| usize::try_from(v).ok())
+            .map(Some)
+            .ok_or_else(|| invalid(key, value)),
+    }
+}
+
+fn invalid(key: &str, value: &Value) -> FormatterError {
+    FormatterError::new(format!("Invalid {key}: {value}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::Color;
+
+    fn text(s: &str) -> Cell {
+        Cell::default().native_with_content(Content::new(ContentKind::Text(s.to_string())))
+    }
+
+    fn to_json(table: &Table) -> String {
+        let mut v = Vec::new();
+        table.native_to_json(&mut v, true).unwrap();
+        String::from_utf8(v).unwrap()
+    }
+
+    #[test]
+    fn to_json_is_loaded_back() {
+        let splitter = Cell::default().native_with_content(Content::new(ContentKind::Splitter));
+        let table = Table::new(vec![
+            vec![
+                text("a\nb")
+                    .native_with_merge(Some(1))
+                    .native_with_align(Align::Center)
+                    .native_with_formatter(vec![Formatter::Bold, Formatter::color(Color::Red)]),
+                Cell::default(),
+                text("c")
+                    .native_with_row_span(Some(2))
+                    .native_with_valign(VAlign::Bottom)
+                    .native_with_padding(Padding::new(0, 2)),
+            ],
+            vec![splitter.clone(), splitter, Cell::default()],
+            vec![
+                text("d").native_with_overflow(Overflow::WrapChar).native_with_width(Some(3)),
+                text("e").native_with_formatter(vec![Formatter::rbg_color(1, 2, 3)]),
+                Cell::default(),
+            ],
+        ])
+        .native_with_border(Border::All.native_with_style(BorderStyle::Rounded))
+        .native_with_columns(vec![
+            Column::default()
+                .native_with_header(Some("x".to_string()))
+                .native_with_align(Some(Align::Right))
+                .native_with_min_width(Some(2)),
+        ])
+        .native_with_column_separator(true)
+        .native_with_max_width(Some(40))
+        .native_with_shrink(Shrink::priority(vec![2, 0], vec![1, 1]))
+        .native_with_fit_terminal(true)
+        .native_with_markdown_merge(MarkdownMerge::Html);
+        let json = to_json(&table);
+        let loaded = parse(&json).unwrap();
+        assert_eq!(to_json(&loaded), json);
+        let value: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["table"][2][0]["overflow"], "wrap_char");
+        assert_eq!(value["border"]["style"], "rounded");
+    }
+
+    #[test]
+    fn unset_cell_fields_are_kept() {
+        let table = Table::new(vec![vec![text("a"), text("b").native_with_align(Align::Left)]]);
+        let value: Value = serde_json::from_str(&to_json(&table)).unwrap();
+        assert_eq!(value["table"][0][0]["align"], Value::Null);
+        assert_eq!(value["table"][0][1]["align"], "left");
+        let loaded = parse(&to_json(&table)).unwrap();
+        assert_eq!(loaded.get_table()[0][0].get_explicit_align(), None);
+        assert_eq!(loaded.get_table()[0][1].get_explicit_align(), Some(Align::Left));
+    }
+}
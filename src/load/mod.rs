@@ -0,0 +1,4 @@
+//! Loaders creating tables from other formats.
+
+pub mod csv;
+pub mod json;
@@ -0,0 +1,188 @@
+//! Read the styles of ANSI escape sequences, so the output of `Renderer.Normal` could be exported with its colors.
+
+use crate::{
+    errors::TableError,
+    render::{
+        escapes, lines,
+        text::{self, Paint},
+    },
+    settings::{Color, Style},
+    table::{Column, Table},
+};
+
+/// The colors of SGR codes 30 to 37 and 90 to 97, which are also the first 16 colors of the 256 color palette.
+const PALETTE: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::White,
+    Color::BrightBlack,
+    Color::BrightRed,
+    Color::BrightGreen,
+    Color::BrightYellow,
+    Color::BrightBlue,
+    Color::BrightMagenta,
+    Color::BrightCyan,
+    Color::BrightWhite,
+];
+
+/// Render the table like `Renderer.Normal`, with SGR sequences for the styles of its formatters even when stdout is not
+/// a terminal or `NO_COLOR` is set, since they are built from `Style` instead of `colored`.
+/// `columns` are the columns of the table before they were applied to its cells.
+pub fn render_normal(table: &Table, columns: &[Column]) -> Result<String, TableError> {
+    let mut v = Vec::new();
+    text::render(table, &mut v, columns, Paint::Always)?;
+    Ok(String::from_utf8_lossy(&v).into_owned())
+}
+
+/// Wrap text in the SGR sequences of a style, which `styled` reads back.
+pub fn paint(text: &str, style: &Style) -> String {
+    let mut codes = Vec::new();
+    for (flag, code) in [
+        (style.bold, "1"),
+        (style.dimmed, "2"),
+        (style.italic, "3"),
+        (style.underline, "4"),
+        (style.blink, "5"),
+        (style.reversed, "7"),
+        (style.hidden, "8"),
+        (style.strikethrough, "9"),
+    ] {
+        if flag {
+            codes.push(code.to_string());
+        }
+    }
+    if let Some((r, g, b)) = style.foreground {
+        codes.push(format!("38;2;{r};{g};{b}"));
+    }
+    if let Some((r, g, b)) = style.background {
+        codes.push(format!("48;2;{r};{g};{b}"));
+    }
+    if codes.is_empty() {
+        text.to_string()
+    } else {
+        format!("\x1b[{}m{text}\x1b[0m", codes.join(";"))
+    }
+}
+
+/// Split text into lines of runs with the same style, following the SGR escape sequences in it.
+///
+/// A style goes on until it is reset, even across lines. Other escape sequences, like hyperlinks, are dropped.
+pub fn styled(text: &str) -> Vec<Vec<(Style, &str)>> {
+    let mut style = Style::default();
+    lines(text)
+        .map(|line| {
+            let mut runs = Vec::new();
+            for (s, escape) in escapes(line) {
+                if !escape {
+                    runs.push((style, s));
+                } else if let Some(params) = s.strip_prefix("\x1b[").and_then(|s| s.strip_suffix('m')) {
+                    sgr(&mut style, params);
+                }
+            }
+            runs
+        })
+        .collect()
+}
+
+/// Apply the parameters of a SGR sequence, e.g. `1;31` of `ESC[1;31m`.
+fn sgr(style: &mut Style, params: &str) {
+    let codes: Vec<u16> = params.split(';').map(|p| p.parse().unwrap_or(0)).collect();
+    let mut codes = codes.iter().copied();
+    while let Some(code) = codes.next() {
+        match code {
+            0 => *style = Style::default(),
+            1 => style.bold = true,
+            2 => style.dimmed = true,
+            3 => style.italic = true,
+            4 => style.underline = true,
+            5 | 6 => style.blink = true,
+            7 => style.reversed = true,
+            8 => style.hidden = true,
+            9 => style.strikethrough = true,
+            22 => (style.bold, style.dimmed) = (false, false),
+            23 => style.italic = false,
+            24 => style.underline = false,
+            25 => style.blink = false,
+            27 => style.reversed = false,
+            28 => style.hidden = false,
+            29 => style.strikethrough = false,
+            30..=37 => style.foreground = Some(PALETTE[code as usize - 30].to_rgb()),
+            38 => style.foreground = extended(&mut codes),
+            39 => style.foreground = None,
+            40..=47 => style.background = Some(PALETTE[code as usize - 40].to_rgb()),
+            48 => style.background = extended(&mut codes),
+            49 => style.background = None,
+            90..=97 => style.foreground = Some(PALETTE[code as usize - 82].to_rgb()),
+            100..=107 => style.background = Some(PALETTE[code as usize - 92].to_rgb()),
+            _ => {}
+        }
+    }
+}
+
+/// Read the color of `38` and `48`, which is `5;n` in the 256 color palette or `2;r;g;b`.
+fn extended(codes: &mut impl Iterator<Item = u16>) -> Option<(u8, u8, u8)> {
+    let mut next = || codes.next().map(|c| c.min(255) as u8);
+    match next()? {
+        2 => Some((next()?, next()?, next()?)),
+        5 => Some(match next()? {
+            n @ 0..=15 => PALETTE[n as usize].to_rgb(),
+            n @ 16..=231 => {
+                let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+                let n = n - 16;
+                (level(n / 36), level(n / 6 % 6), level(n % 6))
+            }
+            n => {
+                let gray = 8 + (n - 232) * 10;
+                (gray, gray, gray)
+            }
+        }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paint_is_read_back() {
+        let style = Style {
+            foreground: Some((1, 2, 3)),
+            background: Some((4, 5, 6)),
+            bold: true,
+            italic: true,
+            underline: true,
+            strikethrough: true,
+            ..Style::default()
+        };
+        let text = format!("{}|", paint("a b", &style));
+        assert_eq!(styled(&text), vec![vec![(style, "a b"), (Style::default(), "|")]]);
+        assert_eq!(paint("a", &Style::default()), "a");
+    }
+
+    #[test]
+    fn styles_go_on_across_lines() {
+        let rows = styled("\x1b[1;31ma\nb\x1b[22;39mc");
+        let red = Style {
+            foreground: Some(Color::Red.to_rgb()),
+            bold: true,
+            ..Style::default()
+        };
+        assert_eq!(rows, vec![vec![(red, "a")], vec![(red, "b"), (Style::default(), "c")]]);
+    }
+
+    #[test]
+    fn extended_colors() {
+        let foreground = |s: &str| styled(s)[0][0].0.foreground;
+        assert_eq!(foreground("\x1b[38;5;9mx"), Some(Color::BrightRed.to_rgb()));
+        assert_eq!(foreground("\x1b[38;5;208mx"), Some((255, 135, 0)));
+        assert_eq!(foreground("\x1b[38;5;232mx"), Some((8, 8, 8)));
+        assert_eq!(foreground("\x1b[38;2;10;20;30mx"), Some((10, 20, 30)));
+        assert_eq!(foreground("\x1b[94mx"), Some(Color::BrightBlue.to_rgb()));
+    }
+}
@@ -0,0 +1,166 @@
+//! Render a table as an AsciiDoc `|===` block.
+//!
+//! The `cols` attribute takes the alignment of each column from `column_aligns`, and a cell which is aligned otherwise
+//! has a `merge` or has a `row_span` is written with a specifier, like `2+|` for a cell merged over two columns.
+//! If the table has a header, its first row becomes the header of the block, since AsciiDoc has only one header row.
+//! Splitter rows are dropped, and neither formatters nor ANSI escape sequences are rendered. `|` in text is escaped,
+//! and line breaks become ` +`.
+
+use std::io;
+
+use itertools::Itertools;
+
+use crate::{
+    errors::TableError,
+    render::{column_aligns, is_splitter_row, layout, lines, strip_escapes, validate},
+    settings::AlignKind,
+    table::{Cell, ContentKind, Table},
+};
+
+pub fn render(table: &Table, writer: &mut impl io::Write) -> Result<(), TableError> {
+    let w = validate(table)?;
+    let layout = layout(table)?;
+    let rows = table.get_table();
+    let aligns = column_aligns(table, w);
+    let header = rows.iter().position(|r| is_splitter_row(r)).unwrap_or(0) > 0;
+    let mut attributes = Vec::new();
+    if w > 0 {
+        attributes.push(format!("cols=\"{}\"", aligns.iter().map(|a| spec(*a)).join(",")));
+    }
+    if header {
+        attributes.push("options=\"header\"".to_string());
+    }
+    if !attributes.is_empty() {
+        writeln!(writer, "[{}]", attributes.join(","))?;
+    }
+    writeln!(writer, "|===")?;
+    for (y, row) in rows.iter().enumerate() {
+        if is_splitter_row(row) {
+            continue;
+        }
+        let line = layout[y]
+            .iter()
+            // The rows below a `row_span` leave its columns out.
+            .filter(|span| span.y == y)
+            .map(|span| {
+                let count = rows[y..y + span.rows].iter().filter(|r| !is_splitter_row(r)).count();
+                let span_spec = match (span.columns, count) {
+                    (1, 1) => String::new(),
+                    (columns, 1) => format!("{columns}+"),
+                    (1, count) => format!(".{count}+"),
+                    (columns, count) => format!("{columns}.{count}+"),
+                };
+                let align = span.cell.get_align().kind();
+                let align_spec = if aligns.get(span.x) != Some(&align) { spec(align) } else { "" };
+                format!("{span_spec}{align_spec}|{}", text(span.cell))
+            })
+            .join(" ");
+        writeln!(writer, "{line}")?;
+        // A blank line ends the header row.
+        if header && y == 0 {
+            writeln!(writer)?;
+        }
+    }
+    writeln!(writer, "|===")?;
+    Ok(())
+}
+
+fn spec(align: AlignKind) -> &'static str {
+    match align {
+        AlignKind::Left => "<",
+        AlignKind::Center => "^",
+        AlignKind::Right => ">",
+    }
+}
+
+fn text(cell: &Cell) -> String {
+    match cell.get_content().kind() {
+        ContentKind::Text(ref text) => lines(&strip_escapes(text)).map(|l| l.replace('|', "\\|")).join(" +\n"),
+        ContentKind::Splitter | ContentKind::None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{settings::Align, table::Content};
+
+    fn text(s: &str) -> Cell {
+        Cell::default().native_with_content(Content::new(ContentKind::Text(s.to_string())))
+    }
+
+    fn splitter() -> Cell {
+        Cell::default().native_with_content(Content::Splitter)
+    }
+
+    fn rendered(table: &Table) -> String {
+        let mut v = Vec::new();
+        render(table, &mut v).unwrap();
+        String::from_utf8(v).unwrap()
+    }
+
+    #[test]
+    fn plain_table() {
+        let table = Table::new(vec![
+            vec![text("name"), text("size")],
+            vec![splitter(), splitter()],
+            vec![text("a|b"), text("1").native_with_align(Align::Right)],
+            vec![text("c\nd"), text("22").native_with_align(Align::Center)],
+        ]);
+        // The header cell is aligned otherwise than its column, which takes the alignment of the body.
+        assert_eq!(
+            rendered(&table),
+            "[cols=\"<,>\",options=\"header\"]\n|===\n|name <|size\n\n|a\\|b |1\n|c +\nd ^|22\n|===\n"
+        );
+        let table = Table::new(vec![vec![text("a"), text("b")]]);
+        assert_eq!(rendered(&table), "[cols=\"<,<\"]\n|===\n|a |b\n|===\n");
+    }
+
+    #[test]
+    fn merge() {
+        let table = Table::new(vec![
+            vec![text("head").native_with_merge(Some(1)), Cell::default(), text("c")],
+            vec![splitter(); 3],
+            vec![text("a"), text("wide cell").native_with_merge(Some(1)), Cell::default()],
+            vec![text("x"), text("y"), text("z")],
+        ]);
+        assert_eq!(
+            rendered(&table),
+            "[cols=\"<,<,<\",options=\"header\"]\n|===\n2+|head |c\n\n|a 2+|wide cell\n|x |y |z\n|===\n"
+        );
+    }
+
+    #[test]
+    fn row_span() {
+        let table = Table::new(vec![
+            vec![text("h1"), text("h2"), text("h3")],
+            vec![splitter(); 3],
+            vec![text("tall").native_with_row_span(Some(1)), text("x"), text("1")],
+            vec![Cell::default(), text("y"), text("2")],
+            vec![text("both").native_with_row_span(Some(1)).native_with_merge(Some(1)), Cell::default(), text("3")],
+            vec![Cell::default(), Cell::default(), text("4")],
+        ]);
+        assert_eq!(
+            rendered(&table),
+            "[cols=\"<,<,<\",options=\"header\"]\n|===\n|h1 |h2 |h3\n\n.2+|tall |x |1\n|y |2\n2.2+|both |3\n|4\n|===\n"
+        );
+    }
+
+    #[test]
+    fn splitter_rows_are_dropped() {
+        // A `row_span` counts only the rows which are not splitters.
+        let table = Table::new(vec![
+            vec![text("a"), text("b")],
+            vec![text("c"), text("d")],
+            vec![splitter(), splitter()],
+            vec![text("e").native_with_row_span(Some(2)), text("f")],
+            vec![Cell::default(), splitter()],
+            vec![Cell::default(), text("g")],
+        ]);
+        // AsciiDoc has only one header row, so the second one goes to the body.
+        assert_eq!(
+            rendered(&table),
+            "[cols=\"<,<\",options=\"header\"]\n|===\n|a |b\n\n|c |d\n.2+|e |f\n|g\n|===\n"
+        );
+    }
+}
@@ -0,0 +1,111 @@
+//! Export a table as csv, following RFC 4180.
+//!
+//! Splitter rows are dropped, and formatters are ignored.
+
+use std::io;
+
+use crate::{
+    errors::TableError,
+    render::{is_splitter_row, layout},
+    settings::QuoteStyle,
+    table::{ContentKind, Table},
+};
+
+/// When `fill_merge` is set, the content of a merged cell is repeated in every column and row it covers, otherwise these fields are left blank.
+pub fn render(
+    table: &Table,
+    writer: &mut impl io::Write,
+    delimiter: char,
+    quote_style: QuoteStyle,
+    fill_merge: bool,
+) -> Result<(), TableError> {
+    let layout = layout(table)?;
+    for (y, (row, spans)) in table.get_table().iter().zip(layout).enumerate() {
+        if is_splitter_row(row) {
+            continue;
+        }
+        let mut fields = Vec::with_capacity(row.len());
+        for span in spans {
+            let text = match span.cell.get_content().kind() {
+                ContentKind::Text(ref text) => text.as_str(),
+                ContentKind::Splitter | ContentKind::None => "",
+            };
+            for cx in 0..span.columns {
+                fields.push(if fill_merge || (cx == 0 && span.y == y) {
+                    quote(text, delimiter, quote_style)
+                } else {
+                    quote("", delimiter, quote_style)
+                });
+            }
+        }
+        write!(writer, "{}\r\n", fields.join(&delimiter.to_string()))?;
+    }
+    Ok(())
+}
+
+fn quote(field: &str, delimiter: char, quote_style: QuoteStyle) -> String {
+    let needed = match quote_style {
+        QuoteStyle::Always => true,
+        QuoteStyle::Never => false,
+        QuoteStyle::Necessary => field
+            .chars()
+            .any(|c| c == delimiter || matches!(c, '"' | '\r' | '\n')),
+        QuoteStyle::NonNumeric => !is_number(field),
+    };
+    if needed {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Whether the field is a decimal number, like `-1`, `2.5`, `.5` or `1e-3`.
+///
+/// Unlike `f64::from_str`, `NaN`, `inf` and `infinity` are not numbers, and neither are blanks around the digits.
+fn is_number(field: &str) -> bool {
+    let digits = |s: &str| s.len() - s.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let s = field.strip_prefix(['+', '-']).unwrap_or(field);
+    let int = digits(s);
+    let s = &s[int..];
+    let (frac, s) = match s.strip_prefix('.') {
+        Some(s) => (digits(s), &s[digits(s)..]),
+        None => (0, s),
+    };
+    if int + frac == 0 {
+        return false;
+    }
+    match s.strip_prefix(['e', 'E']) {
+        Some(s) => {
+            let s = s.strip_prefix(['+', '-']).unwrap_or(s);
+            digits(s) > 0 && digits(s) == s.len()
+        }
+        None => s.is_empty(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers() {
+        for field in ["0", "-1", "+2", "2.5", "2.", ".5", "1e3", "1E-3", "-1.5e+10", "007"] {
+            assert!(is_number(field), "{field}");
+        }
+        for field in [
+            "", "-", ".", "e3", "1e", "1e+", "1.2.3", " 1", "1 ", "NaN", "nan", "inf", "-inf", "infinity", "0x10", "1_000",
+        ] {
+            assert!(!is_number(field), "{field}");
+        }
+    }
+
+    #[test]
+    fn non_numeric_quotes_special_floats() {
+        let quoted = |s| quote(s, ',', QuoteStyle::NonNumeric);
+        assert_eq!(quoted("1.5"), "1.5");
+        assert_eq!(quoted("NaN"), "\"NaN\"");
+        assert_eq!(quoted("inf"), "\"inf\"");
+        assert_eq!(quoted("Infinity"), "\"Infinity\"");
+        assert_eq!(quoted(""), "\"\"");
+    }
+}
@@ -0,0 +1,311 @@
+//! Render a table as html.
+//!
+//! Rows above the first splitter row are rendered in `<thead>`, and every splitter row after that starts a new `<tbody>`.
+//! A `rowspan` cannot leave its `<thead>` or `<tbody>`, so a `row_span` covering a splitter row is cut there,
+//! and goes on as an empty cell in the first row of the next section.
+//! ANSI escape sequences in text are dropped, since the formatters are rendered as styles instead.
+
+use std::{io, ops::Range};
+
+use itertools::Itertools;
+
+use crate::{
+    errors::TableError,
+    render::{ansi, is_splitter_row, layout, lines, strip_escapes, Span},
+    settings::{AlignKind, BorderStyle, Style, VAlign},
+    table::{Cell, Column, ContentKind, Table},
+};
+
+pub fn render(table: &Table, writer: &mut impl io::Write) -> Result<(), TableError> {
+    let layout = layout(table)?;
+    let rows = table.get_table();
+    let border = table.get_border();
+    let line = match table.get_border().get_style() {
+        BorderStyle::Double => "3px double",
+        BorderStyle::Heavy => "2px solid",
+        _ => "1px solid",
+    };
+    let border_style = [
+        (border.get_top(), "border-top"),
+        (border.get_bottom(), "border-bottom"),
+        (border.get_left(), "border-left"),
+        (border.get_right(), "border-right"),
+    ]
+    .iter()
+    .filter(|(flag, _)| *flag)
+    .map(|(_, side)| format!("{side}: {line}"))
+    .join("; ");
+    if border_style.is_empty() {
+        writeln!(writer, "<table>")?;
+    } else {
+        writeln!(
+            writer,
+            "<table style=\"border-collapse: collapse; {}\">",
+            border_style
+        )?;
+    }
+
+    // Column separators are the left border of every cell but the first one in a row.
+    let separator = table.get_column_separator().then_some(line);
+
+    let (head, body) = match rows.iter().position(|r| is_splitter_row(r)) {
+        Some(h) => (&rows[..h], h + 1),
+        None => (&rows[..0], 0),
+    };
+    if !head.is_empty() {
+        writeln!(writer, "  <thead>")?;
+        for (y, spans) in layout.iter().enumerate().take(head.len()) {
+            render_row(spans, y, 0..head.len(), "th", separator, writer)?;
+        }
+        writeln!(writer, "  </thead>")?;
+    }
+    let mut y = body;
+    for group in rows[body..].split(|r| is_splitter_row(r)) {
+        if !group.is_empty() {
+            writeln!(writer, "  <tbody>")?;
+            for gy in 0..group.len() {
+                render_row(&layout[y + gy], y + gy, y..y + group.len(), "td", separator, writer)?;
+            }
+            writeln!(writer, "  </tbody>")?;
+        }
+        y += group.len() + 1;
+    }
+
+    writeln!(writer, "</table>")?;
+    Ok(())
+}
+
+/// Render a row of the section `section`, which has no splitter rows.
+///
+/// Cells with a `row_span` from an earlier section start again in its first row, without their text.
+fn render_row(
+    spans: &[Span],
+    y: usize,
+    section: Range<usize>,
+    tag: &str,
+    separator: Option<&str>,
+    writer: &mut impl io::Write,
+) -> Result<(), TableError> {
+    writeln!(writer, "    <tr>")?;
+    for span in spans.iter().filter(|s| s.y == y || (y == section.start && s.y < y)) {
+        let &Span { x, cell, columns, .. } = span;
+        let mut attributes = String::new();
+        if columns > 1 {
+            attributes.push_str(&format!(" colspan=\"{columns}\""));
+        }
+        let row_span = (span.y + span.rows).min(section.end) - y;
+        if row_span > 1 {
+            attributes.push_str(&format!(" rowspan=\"{row_span}\""));
+        }
+        let text = match cell.get_content().kind() {
+            ContentKind::Text(ref text) if span.y == y => lines(&strip_escapes(text)).map(escape).join("<br>"),
+            _ => String::new(),
+        };
+        writeln!(
+            writer,
+            "      <{tag}{attributes} style=\"{}\">{text}</{tag}>",
+            cell_style(cell, separator.filter(|_| x > 0))
+        )?;
+    }
+    writeln!(writer, "    </tr>")?;
+    Ok(())
+}
+
+fn cell_style(cell: &Cell, separator: Option<&str>) -> String {
+    let mut v = vec![format!(
+        "text-align: {}",
+        match cell.get_align().kind() {
+            AlignKind::Left => "left",
+            AlignKind::Center => "center",
+            AlignKind::Right => "right",
+        }
+    )];
+    // Browsers center cells vertically by default, while terminals put them at the top.
+    v.push(format!(
+        "vertical-align: {}",
+        match cell.get_valign() {
+            VAlign::Top => "top",
+            VAlign::Middle => "middle",
+            VAlign::Bottom => "bottom",
+        }
+    ));
+    let padding = cell.get_padding();
+    if padding.get_left() > 0 {
+        v.push(format!("padding-left: {}ch", padding.get_left()));
+    }
+    if padding.get_right() > 0 {
+        v.push(format!("padding-right: {}ch", padding.get_right()));
+    }
+    if let Some(line) = separator {
+        v.push(format!("border-left: {line}"));
+    }
+    let css = css(&Style::from_formatters(cell.get_formatter()));
+    if !css.is_empty() {
+        v.push(css);
+    }
+    v.join("; ")
+}
+
+/// Render the table like `Renderer.Normal` in a `<pre>` block, with `<span>` elements styled by `css` in place of its
+/// ANSI escape sequences, so it looks the same as in a terminal.
+///
+/// The styles are built from the formatters by `ansi::render_normal`, so they are kept when stdout is not a terminal
+/// or `NO_COLOR` is set, and the override of `colored` is left alone.
+pub fn render_pre(table: &Table, writer: &mut impl io::Write, columns: &[Column]) -> Result<(), TableError> {
+    let output = ansi::render_normal(table, columns)?;
+    let mut rows = ansi::styled(&output);
+    if rows.last().is_some_and(|r| r.is_empty()) {
+        rows.pop();
+    }
+    let lines = rows
+        .iter()
+        .map(|runs| {
+            runs.chunk_by(|a, b| a.0 == b.0)
+                .map(|runs| {
+                    let text = escape(&runs.iter().map(|(_, s)| *s).collect::<String>());
+                    let css = css(&runs[0].0);
+                    if css.is_empty() {
+                        text
+                    } else {
+                        format!("<span style=\"{css}\">{text}</span>")
+                    }
+                })
+                .collect::<String>()
+        })
+        .join("\n");
+    writeln!(writer, "<pre>{lines}</pre>")?;
+    Ok(())
+}
+
+/// Translate a style into inline css declarations.
+pub fn css(style: &Style) -> String {
+    let mut v = Vec::new();
+    let (foreground, background) = if style.reversed {
+        (style.background, style.foreground)
+    } else {
+        (style.foreground, style.background)
+    };
+    if let Some((r, g, b)) = foreground {
+        v.push(format!("color: #{r:02x}{g:02x}{b:02x}"));
+    }
+    if let Some((r, g, b)) = background {
+        v.push(format!("background-color: #{r:02x}{g:02x}{b:02x}"));
+    }
+    if style.bold {
+        v.push("font-weight: bold".to_string());
+    }
+    if style.dimmed {
+        v.push("opacity: 0.5".to_string());
+    }
+    if style.italic {
+        v.push("font-style: italic".to_string());
+    }
+    let decoration = [
+        (style.underline, "underline"),
+        (style.strikethrough, "line-through"),
+        (style.blink, "blink"),
+    ]
+    .iter()
+    .filter(|(flag, _)| *flag)
+    .map(|(_, d)| *d)
+    .join(" ");
+    if !decoration.is_empty() {
+        v.push(format!("text-decoration: {decoration}"));
+    }
+    if style.hidden {
+        v.push("visibility: hidden".to_string());
+    }
+    v.join("; ")
+}
+
+/// Escape html special characters.
+pub fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for ch in s.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        settings::{Color, Formatter, Renderer},
+        table::Content,
+    };
+
+    fn text(s: &str) -> Cell {
+        Cell::default().native_with_content(Content::new(ContentKind::Text(s.to_string())))
+    }
+
+    #[test]
+    fn row_span_is_cut_at_splitter_rows() {
+        let splitter = Cell::default().native_with_content(Content::new(ContentKind::Splitter));
+        let table = Table::new(vec![
+            vec![text("a").native_with_row_span(Some(3)), text("b")],
+            vec![Cell::default(), splitter],
+            vec![Cell::default(), text("c")],
+            vec![Cell::default(), text("d")],
+        ]);
+        let mut v = Vec::new();
+        render(&table, &mut v).unwrap();
+        let html = String::from_utf8(v).unwrap();
+        let (head, body) = html.split_once("</thead>").unwrap();
+        assert!(!head.contains("rowspan"), "{html}");
+        assert!(head.contains(">a</th>"), "{html}");
+        assert!(body.contains("<td rowspan=\"2\" style=\"text-align: left; vertical-align: top\"></td>"), "{html}");
+        assert_eq!(body.matches("rowspan").count(), 1, "{html}");
+        assert_eq!(body.matches("<td").count(), 3, "{html}");
+    }
+
+    #[test]
+    fn pre_keeps_styles_of_formatters() {
+        let cell = text("a<b").native_with_formatter(vec![Formatter::Bold, Formatter::color(Color::Red)]);
+        let table = Table::new(vec![vec![cell]]);
+        let mut v = Vec::new();
+        render_pre(&table, &mut v, &[]).unwrap();
+        let html = String::from_utf8(v).unwrap();
+        let (r, g, b) = Color::Red.to_rgb();
+        let span = format!("<span style=\"color: #{r:02x}{g:02x}{b:02x}; font-weight: bold\"> a&lt;b </span>");
+        assert!(html.starts_with("<pre>"), "{html}");
+        assert!(html.contains(&span), "{html}");
+        assert!(!html.contains('\x1b'), "{html}");
+    }
+
+    #[test]
+    fn markup_drops_ansi_escapes() {
+        let table = Table::new(vec![
+            vec![text("\x1b[1mname\x1b[0m"), text("x")],
+            vec![text("\x1b[31mred\x1b[0m <b>"), text("\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\")],
+        ]);
+        let mut v = Vec::new();
+        render(&table, &mut v).unwrap();
+        let html = String::from_utf8(v).unwrap();
+        assert!(html.contains(">red &lt;b&gt;</td>"), "{html}");
+        assert!(html.contains(">link</td>"), "{html}");
+        for renderer in [
+            Renderer::Markdown,
+            Renderer::Latex,
+            Renderer::RstGrid,
+            Renderer::RstSimple,
+            Renderer::AsciiDoc,
+            Renderer::Org,
+            Renderer::MediaWiki,
+            Renderer::JiraWiki,
+        ] {
+            let mut v = Vec::new();
+            table.native_rendered_by(renderer, &mut v).unwrap();
+            let out = String::from_utf8(v).unwrap();
+            assert!(!out.contains('\x1b'), "{renderer:?}: {out}");
+            assert!(out.contains("name") && out.contains("red") && out.contains("link"), "{renderer:?}: {out}");
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! Render a table as Jira and Confluence wiki markup.
+//!
+//! Rows above the first splitter row are header rows, written with `||`, and other splitter rows are dropped.
+//! The markup has no merged cells, so the text of a cell with a `merge` or a `row_span` is put in its first column
+//! and row, and the cells it covers are left blank. `|` in text is escaped, line breaks become `\\`,
+//! and formatters and ANSI escape sequences are not rendered.
+
+use std::io;
+
+use itertools::Itertools;
+
+use crate::{
+    errors::TableError,
+    render::{is_splitter_row, layout, lines, strip_escapes, validate},
+    table::{Cell, ContentKind, Table},
+};
+
+pub fn render(table: &Table, writer: &mut impl io::Write) -> Result<(), TableError> {
+    let w = validate(table)?;
+    let layout = layout(table)?;
+    let rows = table.get_table();
+    let head = rows.iter().position(|r| is_splitter_row(r)).unwrap_or(0);
+    for (y, row) in rows.iter().enumerate() {
+        if is_splitter_row(row) || w == 0 {
+            continue;
+        }
+        let mut cells = vec![String::new(); w];
+        for span in layout[y].iter().filter(|s| s.y == y) {
+            cells[span.x] = text(span.cell);
+        }
+        let mark = if y < head { "||" } else { "|" };
+        // Empty cells are collapsed, so they take a space.
+        let line = cells
+            .iter()
+            .map(|c| if c.is_empty() { " " } else { c })
+            .join(mark);
+        writeln!(writer, "{mark}{line}{mark}")?;
+    }
+    Ok(())
+}
+
+fn text(cell: &Cell) -> String {
+    match cell.get_content().kind() {
+        ContentKind::Text(ref text) => lines(&strip_escapes(text)).map(|l| l.replace('|', "\\|")).join("\\\\"),
+        ContentKind::Splitter | ContentKind::None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::Content;
+
+    fn text(s: &str) -> Cell {
+        Cell::default().native_with_content(Content::new(ContentKind::Text(s.to_string())))
+    }
+
+    fn splitter() -> Cell {
+        Cell::default().native_with_content(Content::Splitter)
+    }
+
+    fn rendered(table: &Table) -> String {
+        let mut v = Vec::new();
+        render(table, &mut v).unwrap();
+        String::from_utf8(v).unwrap()
+    }
+
+    #[test]
+    fn plain_table() {
+        let table = Table::new(vec![
+            vec![text("name"), text("size")],
+            vec![splitter(), splitter()],
+            vec![text("a|b"), text("1")],
+            vec![text("c\nd"), text("")],
+        ]);
+        assert_eq!(rendered(&table), "||name||size||\n|a\\|b|1|\n|c\\\\d| |\n");
+        assert_eq!(rendered(&Table::new(vec![vec![], vec![]])), "");
+    }
+
+    #[test]
+    fn merged_cells_are_left_blank() {
+        let table = Table::new(vec![
+            vec![text("head").native_with_merge(Some(1)), Cell::default(), text("c")],
+            vec![splitter(); 3],
+            vec![text("tall").native_with_row_span(Some(1)), text("x"), text("1")],
+            vec![Cell::default(), text("y"), text("2")],
+            vec![text("both").native_with_row_span(Some(1)).native_with_merge(Some(1)), Cell::default(), text("3")],
+            vec![Cell::default(), Cell::default(), text("4")],
+        ]);
+        assert_eq!(
+            rendered(&table),
+            "||head|| ||c||\n|tall|x|1|\n| |y|2|\n|both| |3|\n| | |4|\n"
+        );
+    }
+
+    #[test]
+    fn splitter_rows_are_dropped() {
+        let table = Table::new(vec![
+            vec![text("a"), text("b")],
+            vec![text("c"), text("d")],
+            vec![splitter(), splitter()],
+            vec![text("e").native_with_row_span(Some(2)), text("f")],
+            vec![Cell::default(), splitter()],
+            vec![Cell::default(), text("g")],
+        ]);
+        assert_eq!(rendered(&table), "||a||b||\n||c||d||\n|e|f|\n| |g|\n");
+    }
+}
@@ -0,0 +1,98 @@
+//! Export a table as json, keeping every property of the cells, so that it could be loaded again by `Table.from_json`.
+//!
+//! The schema is documented in the "JSON" section of the README.
+
+use std::io;
+
+use itertools::Itertools;
+use serde_json::{json, Value};
+
+use crate::{
+    errors::TableError,
+    settings::{Align, AlignKind, Overflow, Padding, VAlign},
+    table::{Cell, Column, ContentKind, Table},
+};
+
+pub const VERSION: u64 = 1;
+
+pub fn render(table: &Table, writer: &mut impl io::Write, pretty: bool) -> Result<(), TableError> {
+    let value = to_value(table);
+    if pretty {
+        serde_json::to_writer_pretty(&mut *writer, &value).map_err(io::Error::from)?;
+    } else {
+        serde_json::to_writer(&mut *writer, &value).map_err(io::Error::from)?;
+    }
+    Ok(())
+}
+
+pub fn to_value(table: &Table) -> Value {
+    let border = table.get_border();
+    json!({
+        "version": VERSION,
+        "border": {
+            "left": border.get_left(),
+            "right": border.get_right(),
+            "top": border.get_top(),
+            "bottom": border.get_bottom(),
+            "style": table.get_border().get_style().to_json(),
+        },
+        "column_separator": table.get_column_separator(),
+        "max_width": table.get_max_width(),
+        "shrink": table.get_shrink().to_json(),
+        "fit_terminal": table.get_fit_terminal(),
+        "markdown_merge": table.get_markdown_merge().name(),
+        "columns": table.get_columns().iter().map(column_to_value).collect_vec(),
+        "table": table
+            .get_table()
+            .iter()
+            .map(|row| row.iter().map(cell_to_value).collect_vec())
+            .collect_vec(),
+    })
+}
+
+fn column_to_value(column: &Column) -> Value {
+    json!({
+        "align": column.get_align().map(align_name),
+        "width": column.get_width(),
+        "min_width": column.get_min_width(),
+        "max_width": column.get_max_width(),
+        "overflow": column.get_overflow().map(Overflow::name),
+        "padding": column.get_padding().map(padding_to_value),
+        "formatter": column.get_formatter().iter().map(|f| f.to_json()).collect_vec(),
+        "header": column.get_header(),
+    })
+}
+
+fn cell_to_value(cell: &Cell) -> Value {
+    json!({
+        "content": match cell.get_content().kind() {
+            ContentKind::Text(ref text) => json!({ "text": text }),
+            ContentKind::Splitter => json!("splitter"),
+            ContentKind::None => json!("empty"),
+        },
+        "overflow": cell.get_explicit_overflow().map(Overflow::name),
+        "width": cell.get_width(),
+        "align": cell.get_explicit_align().map(align_name),
+        "valign": match cell.get_valign() {
+            VAlign::Top => "top",
+            VAlign::Middle => "middle",
+            VAlign::Bottom => "bottom",
+        },
+        "padding": cell.get_explicit_padding().map(padding_to_value),
+        "merge": cell.get_merge(),
+        "row_span": cell.get_row_span(),
+        "formatter": cell.get_formatter().iter().map(|f| f.to_json()).collect_vec(),
+    })
+}
+
+fn align_name(align: Align) -> &'static str {
+    match align.kind() {
+        AlignKind::Left => "left",
+        AlignKind::Center => "center",
+        AlignKind::Right => "right",
+    }
+}
+
+fn padding_to_value(padding: Padding) -> Value {
+    json!({ "left": padding.get_left(), "right": padding.get_right() })
+}
@@ -0,0 +1,183 @@
+//! Render a table as a LaTeX `tabular` environment.
+//!
+//! Horizontal rules use the `booktabs` package, so the document needs `\usepackage{booktabs}`.
+//! Cells with a `row_span` use `\multirow`, which needs `\usepackage{multirow}`.
+//! A table without columns renders nothing, since a `tabular` needs at least one column.
+//! ANSI escape sequences in text are dropped.
+
+use std::io;
+
+use itertools::Itertools;
+
+use crate::{
+    errors::TableError,
+    render::{column_aligns, is_splitter_row, layout, lines, strip_escapes, validate, Span},
+    settings::{AlignKind, Style},
+    table::{Cell, ContentKind, Table},
+};
+
+pub fn render(table: &Table, writer: &mut impl io::Write) -> Result<(), TableError> {
+    let w = validate(table)?;
+    if w == 0 {
+        return Ok(());
+    }
+    let layout = layout(table)?;
+    let border = table.get_border();
+    let separator = if table.get_column_separator() { "|" } else { "" };
+    let aligns = column_aligns(table, w);
+    writeln!(
+        writer,
+        "\\begin{{tabular}}{{{}{}{}}}",
+        if border.get_left() { "|" } else { "" },
+        aligns.iter().map(|a| spec(*a)).join(separator),
+        if border.get_right() { "|" } else { "" },
+    )?;
+    if border.get_top() {
+        writeln!(writer, "\\toprule")?;
+    }
+    let rows = table.get_table();
+    for (y, row) in rows.iter().enumerate() {
+        if is_splitter_row(row) {
+            // A `row_span` from above crosses the rule, so it only goes under the splitters.
+            if layout[y].iter().any(|s| s.y < y) {
+                let rules = layout[y]
+                    .iter()
+                    .filter(|s| s.y == y)
+                    .map(|s| format!("\\cmidrule{{{}-{}}}", s.x + 1, s.x + s.columns))
+                    .join("");
+                writeln!(writer, "{rules}")?;
+            } else {
+                writeln!(writer, "\\midrule")?;
+            }
+            continue;
+        }
+        let line = layout[y]
+            .iter()
+            .map(|&Span { x, y: cy, cell, columns: span, rows: row_span }| {
+                // The rows below a `\multirow` leave its columns empty.
+                let text = if cy < y {
+                    String::new()
+                } else {
+                    let count = rows[y..y + row_span].iter().filter(|r| !is_splitter_row(r)).count();
+                    if count > 1 {
+                        format!("\\multirow{{{count}}}{{*}}{{{}}}", text(cell))
+                    } else {
+                        text(cell)
+                    }
+                };
+                let align = cell.get_align().kind();
+                if span > 1 || aligns.get(x) != Some(&align) {
+                    format!(
+                        "\\multicolumn{{{span}}}{{{}{}{}}}{{{text}}}",
+                        if x == 0 && border.get_left() { "|" } else { "" },
+                        spec(align),
+                        if x + span < w {
+                            separator
+                        } else if border.get_right() {
+                            "|"
+                        } else {
+                            ""
+                        },
+                    )
+                } else {
+                    text
+                }
+            })
+            .join(" & ");
+        writeln!(writer, "{line} \\\\")?;
+    }
+    if border.get_bottom() {
+        writeln!(writer, "\\bottomrule")?;
+    }
+    writeln!(writer, "\\end{{tabular}}")?;
+    Ok(())
+}
+
+fn spec(align: AlignKind) -> &'static str {
+    match align {
+        AlignKind::Left => "l",
+        AlignKind::Center => "c",
+        AlignKind::Right => "r",
+    }
+}
+
+fn text(cell: &Cell) -> String {
+    let text = match cell.get_content().kind() {
+        ContentKind::Text(ref text) => {
+            let lines = lines(&strip_escapes(text)).map(escape).collect_vec();
+            // Line breaks are not allowed in `l`, `c` and `r` columns, so they are stacked in a box.
+            if lines.len() > 1 {
+                format!(
+                    "\\shortstack[{}]{{{}}}",
+                    spec(cell.get_align().kind()),
+                    lines.join("\\\\")
+                )
+            } else {
+                lines.concat()
+            }
+        }
+        ContentKind::Splitter | ContentKind::None => return String::new(),
+    };
+    let style = Style::from_formatters(cell.get_formatter());
+    [
+        (style.underline, "underline"),
+        (style.italic, "textit"),
+        (style.bold, "textbf"),
+    ]
+    .iter()
+    .filter(|(flag, _)| *flag)
+    .fold(text, |acc, (_, command)| format!("\\{command}{{{acc}}}"))
+}
+
+/// Escape LaTeX special characters.
+pub fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for ch in s.chars() {
+        match ch {
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                out.push('\\');
+                out.push(ch);
+            }
+            '~' => out.push_str("\\textasciitilde{}"),
+            '^' => out.push_str("\\textasciicircum{}"),
+            // These are other glyphs in the OT1 encoding of text.
+            '|' => out.push_str("\\textbar{}"),
+            '<' => out.push_str("\\textless{}"),
+            '>' => out.push_str("\\textgreater{}"),
+            '\\' => out.push_str("\\textbackslash{}"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::Content;
+
+    fn rendered(table: &Table) -> String {
+        let mut v = Vec::new();
+        render(table, &mut v).unwrap();
+        String::from_utf8(v).unwrap()
+    }
+
+    #[test]
+    fn empty_table_renders_nothing() {
+        assert_eq!(rendered(&Table::new(vec![])), "");
+        assert_eq!(rendered(&Table::new(vec![vec![], vec![]])), "");
+    }
+
+    #[test]
+    fn escape_special_characters() {
+        assert_eq!(
+            escape("a|b <c> 50% {x_1} ~^\\"),
+            "a\\textbar{}b \\textless{}c\\textgreater{} 50\\% \\{x\\_1\\} \\textasciitilde{}\\textasciicircum{}\\textbackslash{}"
+        );
+        let cell = Cell::default().native_with_content(Content::new(ContentKind::Text("a|b".to_string())));
+        assert_eq!(
+            rendered(&Table::new(vec![vec![cell]])),
+            "\\begin{tabular}{l}\na\\textbar{}b \\\\\n\\end{tabular}\n"
+        );
+    }
+}
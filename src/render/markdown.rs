@@ -0,0 +1,148 @@
+//! Render a table as markdown.
+//!
+//! The first row is the header, and a splitter row right below it is dropped, since the line of alignment markers
+//! takes its place. The alignment of each column is its `Column.align`, or else the one of its first cell below
+//! the header which is not a splitter or a merged cell, so the header never decides it.
+//! `|` in text is escaped, ANSI escape sequences are dropped, and line breaks become `<br>`, which is rendered by GitHub
+//! and GitLab.
+//!
+//! Markdown tables have no merged cells or splitters, so they are rendered according to `Table.markdown_merge`.
+
+use std::io;
+
+use itertools::Itertools;
+
+use crate::{
+    errors::TableError,
+    render::{first_align, html, is_splitter_row, layout, lines, strip_escapes, validate, Span},
+    settings::{AlignKind, MarkdownMerge},
+    table::{Cell, Column, ContentKind, Table},
+};
+
+/// `columns` are the columns of the table before they were applied to its cells.
+pub fn render(table: &Table, writer: &mut impl io::Write, columns: &[Column]) -> Result<(), TableError> {
+    let w = validate(table)?;
+    let layout = layout(table)?;
+    let rows = table.get_table();
+    let merge = table.get_markdown_merge();
+    // Splitter rows other than the one below the header.
+    let splitters = rows.iter().skip(2).any(|r| is_splitter_row(r));
+    let merged = layout.iter().flatten().any(|s| s.columns > 1 || s.rows > 1);
+    if merge == MarkdownMerge::Html && (splitters || merged) {
+        return html::render(table, writer);
+    }
+    // Markdown has no empty tables.
+    if w == 0 {
+        return Ok(());
+    }
+    let setting_row = (0..w)
+        .map(|x| {
+            columns
+                .get(x)
+                .and_then(|c| c.get_align())
+                .map(|a| a.kind())
+                .or_else(|| first_align(rows[1..].iter(), x))
+                .unwrap_or(AlignKind::Left)
+        })
+        .map(|align| match align {
+            AlignKind::Left => ":--",
+            AlignKind::Center => ":-:",
+            AlignKind::Right => "--:",
+        })
+        .join("|");
+    writeln!(writer, "|{}|", row_text(&layout[0], 0, merge))?;
+    writeln!(writer, "|{}|", setting_row)?;
+    for (y, spans) in layout.iter().enumerate().skip(1) {
+        if y == 1 && is_splitter_row(&rows[1]) {
+            continue;
+        }
+        writeln!(writer, "|{}|", row_text(spans, y, merge))?;
+    }
+    Ok(())
+}
+
+fn row_text(spans: &[Span], y: usize, merge: MarkdownMerge) -> String {
+    spans
+        .iter()
+        .flat_map(|span| {
+            let text = text(span.cell);
+            let repeat = merge == MarkdownMerge::Repeat;
+            // The cell itself, and the cells it covers in the row.
+            let first = if span.y == y || repeat { text.clone() } else { String::new() };
+            let rest = if repeat { text } else { String::new() };
+            std::iter::once(first).chain(std::iter::repeat_n(rest, span.columns - 1))
+        })
+        .join("|")
+}
+
+fn text(cell: &Cell) -> String {
+    match cell.get_content().kind() {
+        ContentKind::None => String::new(),
+        ContentKind::Splitter => "───".to_string(),
+        ContentKind::Text(ref text) => lines(&strip_escapes(text)).map(|l| l.replace('|', "\\|")).join("<br>"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        settings::{Align, Renderer},
+        table::Content,
+    };
+
+    fn text(s: &str) -> Cell {
+        Cell::default().native_with_content(Content::text(s.to_string()))
+    }
+
+    fn delimiters(table: &Table) -> String {
+        let mut v = Vec::new();
+        table.native_rendered_by(Renderer::Markdown, &mut v).unwrap();
+        String::from_utf8(v).unwrap().lines().nth(1).unwrap().to_string()
+    }
+
+    #[test]
+    fn header_without_splitter_does_not_align() {
+        let table = Table::new(vec![
+            vec![text("H1"), text("H2").native_with_align(Align::Center)],
+            vec![text("1"), text("2").native_with_align(Align::Right)],
+        ]);
+        assert_eq!(delimiters(&table), "|:--|--:|");
+    }
+
+    #[test]
+    fn header_with_splitter_does_not_align() {
+        let splitter = Cell::default().native_with_content(Content::Splitter);
+        let table = Table::new(vec![
+            vec![text("H1").native_with_align(Align::Right), text("H2")],
+            vec![splitter.clone(), splitter],
+            vec![text("1"), text("2").native_with_align(Align::Center)],
+        ]);
+        assert_eq!(delimiters(&table), "|:--|:-:|");
+    }
+
+    #[test]
+    fn column_align_goes_first() {
+        let table = Table::new(vec![
+            vec![text("H1"), text("H2")],
+            vec![text("1").native_with_align(Align::Left), text("2").native_with_align(Align::Right)],
+        ])
+        .native_with_columns(vec![Column::default().native_with_align(Some(Align::Center))]);
+        assert_eq!(delimiters(&table), "|:-:|--:|");
+        // Columns with a header put it in row 0.
+        let table = Table::new(vec![vec![text("1"), text("2").native_with_align(Align::Right)]]).native_with_columns(vec![
+            Column::default().native_with_header(Some("H1".to_string())),
+            Column::default().native_with_header(Some("H2".to_string())),
+        ]);
+        assert_eq!(delimiters(&table), "|:--|--:|");
+    }
+
+    #[test]
+    fn empty_table_renders_nothing() {
+        for table in [Table::new(vec![]), Table::new(vec![vec![], vec![]])] {
+            let mut v = Vec::new();
+            table.native_rendered_by(Renderer::Markdown, &mut v).unwrap();
+            assert!(v.is_empty());
+        }
+    }
+}
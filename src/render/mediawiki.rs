@@ -0,0 +1,142 @@
+//! Render a table as MediaWiki markup, in a `{| class="wikitable"` block.
+//!
+//! Rows above the first splitter row are header cells, written with `!`, and other splitter rows are dropped.
+//! `merge` and `row_span` become `colspan` and `rowspan`, and a cell which is not aligned left gets a `text-align` style.
+//! `|` in text is written as `&#124;`, line breaks become `<br />`, and formatters and ANSI escape sequences
+//! are not rendered.
+
+use std::io;
+
+use itertools::Itertools;
+
+use crate::{
+    errors::TableError,
+    render::{is_splitter_row, layout, lines, strip_escapes},
+    settings::AlignKind,
+    table::{Cell, ContentKind, Table},
+};
+
+pub fn render(table: &Table, writer: &mut impl io::Write) -> Result<(), TableError> {
+    let layout = layout(table)?;
+    let rows = table.get_table();
+    let head = rows.iter().position(|r| is_splitter_row(r)).unwrap_or(0);
+    writeln!(writer, "{{| class=\"wikitable\"")?;
+    for (y, row) in rows.iter().enumerate() {
+        if is_splitter_row(row) {
+            continue;
+        }
+        writeln!(writer, "|-")?;
+        let mark = if y < head { "!" } else { "|" };
+        // The rows below a `row_span` leave its columns out.
+        for span in layout[y].iter().filter(|s| s.y == y) {
+            let count = rows[y..y + span.rows].iter().filter(|r| !is_splitter_row(r)).count();
+            let mut attributes = Vec::new();
+            if span.columns > 1 {
+                attributes.push(format!("colspan=\"{}\"", span.columns));
+            }
+            if count > 1 {
+                attributes.push(format!("rowspan=\"{count}\""));
+            }
+            match span.cell.get_align().kind() {
+                AlignKind::Left => {}
+                AlignKind::Center => attributes.push("style=\"text-align: center;\"".to_string()),
+                AlignKind::Right => attributes.push("style=\"text-align: right;\"".to_string()),
+            }
+            let text = text(span.cell);
+            if attributes.is_empty() {
+                writeln!(writer, "{mark} {text}")?;
+            } else {
+                writeln!(writer, "{mark} {} | {text}", attributes.join(" "))?;
+            }
+        }
+    }
+    writeln!(writer, "|}}")?;
+    Ok(())
+}
+
+fn text(cell: &Cell) -> String {
+    match cell.get_content().kind() {
+        ContentKind::Text(ref text) => lines(&strip_escapes(text)).map(|l| l.replace('|', "&#124;")).join("<br />"),
+        ContentKind::Splitter | ContentKind::None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{settings::Align, table::Content};
+
+    fn text(s: &str) -> Cell {
+        Cell::default().native_with_content(Content::new(ContentKind::Text(s.to_string())))
+    }
+
+    fn splitter() -> Cell {
+        Cell::default().native_with_content(Content::Splitter)
+    }
+
+    fn rendered(table: &Table) -> String {
+        let mut v = Vec::new();
+        render(table, &mut v).unwrap();
+        String::from_utf8(v).unwrap()
+    }
+
+    #[test]
+    fn plain_table() {
+        let table = Table::new(vec![
+            vec![text("name"), text("size")],
+            vec![splitter(), splitter()],
+            vec![text("a|b"), text("1").native_with_align(Align::Right)],
+            vec![text("c\nd"), text("22").native_with_align(Align::Center)],
+        ]);
+        assert_eq!(
+            rendered(&table),
+            "{| class=\"wikitable\"\n|-\n! name\n! size\n|-\n| a&#124;b\n| style=\"text-align: right;\" | 1\n\
+             |-\n| c<br />d\n| style=\"text-align: center;\" | 22\n|}\n"
+        );
+    }
+
+    #[test]
+    fn merge_is_colspan() {
+        let table = Table::new(vec![
+            vec![text("head").native_with_merge(Some(1)), Cell::default(), text("c")],
+            vec![splitter(); 3],
+            vec![text("a"), text("wide cell").native_with_merge(Some(1)), Cell::default()],
+        ]);
+        assert_eq!(
+            rendered(&table),
+            "{| class=\"wikitable\"\n|-\n! colspan=\"2\" | head\n! c\n|-\n| a\n| colspan=\"2\" | wide cell\n|}\n"
+        );
+    }
+
+    #[test]
+    fn row_span_is_rowspan() {
+        let table = Table::new(vec![
+            vec![text("tall").native_with_row_span(Some(1)), text("x"), text("1")],
+            vec![Cell::default(), text("y"), text("2")],
+            vec![text("both").native_with_row_span(Some(1)).native_with_merge(Some(1)), Cell::default(), text("3")],
+            vec![Cell::default(), Cell::default(), text("4")],
+        ]);
+        assert_eq!(
+            rendered(&table),
+            "{| class=\"wikitable\"\n|-\n| rowspan=\"2\" | tall\n| x\n| 1\n|-\n| y\n| 2\n\
+             |-\n| colspan=\"2\" rowspan=\"2\" | both\n| 3\n|-\n| 4\n|}\n"
+        );
+    }
+
+    #[test]
+    fn splitter_rows_are_dropped() {
+        // Every row above the first splitter row is a header row, and a `row_span` counts only the other rows.
+        let table = Table::new(vec![
+            vec![text("a"), text("b")],
+            vec![text("c"), text("d")],
+            vec![splitter(), splitter()],
+            vec![text("e").native_with_row_span(Some(2)), text("f")],
+            vec![Cell::default(), splitter()],
+            vec![Cell::default(), text("g")],
+        ]);
+        assert_eq!(
+            rendered(&table),
+            "{| class=\"wikitable\"\n|-\n! a\n! b\n|-\n! c\n! d\n|-\n| rowspan=\"2\" | e\n| f\n|-\n| g\n|}\n"
+        );
+    }
+}
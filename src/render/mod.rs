@@ -0,0 +1,239 @@
+//! Renderers of every `Renderer`, together with the exports of tables.
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    errors::TableError,
+    settings::AlignKind,
+    table::{Cell, ContentKind, Table},
+};
+
+pub mod ansi;
+pub mod asciidoc;
+pub mod csv;
+pub mod html;
+pub mod jira;
+pub mod json;
+pub mod latex;
+pub mod markdown;
+pub mod mediawiki;
+pub mod org;
+pub mod rst;
+pub mod svg;
+pub mod text;
+
+/// Split text into lines at `\n` or `\r\n`. Unlike `str::lines`, a trailing line break starts an empty line.
+pub fn lines(text: &str) -> impl Iterator<Item = &str> {
+    text.split('\n').map(|l| l.strip_suffix('\r').unwrap_or(l))
+}
+
+/// The number of terminal columns the text takes. East Asian wide characters take two columns,
+/// and combining marks and ANSI escape sequences take none.
+pub fn width(text: &str) -> usize {
+    escapes(text)
+        .filter(|(_, escape)| !escape)
+        .map(|(s, _)| s.width())
+        .sum()
+}
+
+/// Split text into grapheme clusters, which are what a reader sees as one character, together with their widths.
+/// ANSI escape sequences are kept whole, with a width of 0.
+pub fn graphemes(text: &str) -> impl Iterator<Item = (&str, usize)> {
+    escapes(text).flat_map(|(s, escape)| {
+        let v: Vec<_> = if escape {
+            vec![(s, 0)]
+        } else {
+            s.graphemes(true).map(|g| (g, g.width())).collect()
+        };
+        v
+    })
+}
+
+/// Split text into ANSI escape sequences and the text between them, with whether each part is an escape sequence.
+///
+/// Both CSI sequences, like the SGR ones which set colors, and OSC sequences, like hyperlinks, are recognized.
+pub fn escapes(text: &str) -> impl Iterator<Item = (&str, bool)> {
+    let mut rest = text;
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        let (len, escape) = match escape_len(rest) {
+            Some(len) => (len, true),
+            None => {
+                let next = rest.char_indices().skip(1).find(|(_, c)| *c == '\x1b');
+                (next.map_or(rest.len(), |(i, _)| i), false)
+            }
+        };
+        let (part, tail) = rest.split_at(len);
+        rest = tail;
+        Some((part, escape))
+    })
+}
+
+/// The text without its ANSI escape sequences, for renderers which write markup, where they would show up as garbage.
+pub fn strip_escapes(text: &str) -> String {
+    escapes(text).filter(|(_, escape)| !escape).map(|(s, _)| s).collect()
+}
+
+/// The length of the escape sequence at the start of the text, if there is one.
+fn escape_len(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    if bytes.first() != Some(&0x1b) {
+        return None;
+    }
+    match bytes.get(1)? {
+        // CSI: parameters and intermediates, then a final byte in `@`..=`~`.
+        b'[' => bytes[2..]
+            .iter()
+            .position(|b| (0x40..=0x7e).contains(b))
+            .map(|i| i + 3),
+        // OSC: ended by BEL or ST.
+        b']' => bytes[2..].iter().enumerate().find_map(|(i, b)| match b {
+            0x07 => Some(i + 3),
+            0x1b if bytes.get(i + 3) == Some(&b'\\') => Some(i + 4),
+            _ => None,
+        }),
+        0x40..=0x5f => Some(2),
+        _ => None,
+    }
+}
+
+/// Check if a row is a horizontal splitter, which means it has `Content.Splitter` cells,
+/// and the other cells in it are empty, e.g. covered by a merged splitter or a `row_span` from above.
+pub fn is_splitter_row(row: &[Cell]) -> bool {
+    row.iter().any(is_splitter)
+        && row.iter().all(|c| {
+            matches!(
+                c.get_content().kind(),
+                ContentKind::Splitter | ContentKind::None
+            )
+        })
+}
+
+pub fn is_splitter(cell: &Cell) -> bool {
+    matches!(cell.get_content().kind(), ContentKind::Splitter)
+}
+
+/// A visible cell, and the area it covers.
+#[derive(Clone, Copy)]
+pub struct Span<'a> {
+    /// Column of the cell.
+    pub x: usize,
+    /// Row of the cell, which is above the current row if the cell has a `row_span`.
+    pub y: usize,
+    pub cell: &'a Cell,
+    /// Number of columns covered, from `merge`.
+    pub columns: usize,
+    /// Number of rows covered, from `row_span`.
+    pub rows: usize,
+}
+
+/// Collect the visible cells of every row, with the area they cover.
+///
+/// A cell with a `row_span` appears in every row it covers, with the `y` of the row it is in.
+/// Cells covered by a `merge` or a `row_span` are skipped, and they must be empty.
+pub fn layout(table: &Table) -> Result<Vec<Vec<Span<'_>>>, TableError> {
+    validate(table)?;
+    let rows = table.get_table();
+    let mut v: Vec<Vec<Span>> = Vec::with_capacity(rows.len());
+    // Cells with a `row_span` still covering the current row.
+    let mut blocks: Vec<Span> = Vec::new();
+    for (y, row) in rows.iter().enumerate() {
+        blocks.retain(|b| b.y + b.rows > y);
+        let mut spans = Vec::new();
+        let mut x = 0;
+        while let Some(cell) = row.get(x) {
+            let span = match blocks.iter().find(|b| b.x == x) {
+                Some(block) => *block,
+                None => {
+                    let merge = cell.get_merge().unwrap_or(0);
+                    let row_span = cell.get_row_span().unwrap_or(0);
+                    if x + merge >= row.len() {
+                        Err(TableError::MergeOutOfTable {
+                            x,
+                            y,
+                            merge,
+                            max: row.len() - x - 1,
+                        })?
+                    }
+                    if y + row_span >= rows.len() {
+                        Err(TableError::MergeOutOfTable {
+                            x,
+                            y,
+                            merge: row_span,
+                            max: rows.len() - y - 1,
+                        })?
+                    }
+                    let span = Span {
+                        x,
+                        y,
+                        cell,
+                        columns: merge + 1,
+                        rows: row_span + 1,
+                    };
+                    if row_span > 0 {
+                        blocks.push(span);
+                    }
+                    span
+                }
+            };
+            // Cells covered by the span, or cells that start a span inside a row span from above.
+            let covered = if span.y == y { x + 1 } else { x };
+            for (cx, c) in row.iter().enumerate().take(x + span.columns).skip(covered) {
+                if !matches!(c.get_content().kind(), ContentKind::None)
+                    || (cx > x && blocks.iter().any(|b| b.x == cx))
+                {
+                    Err(TableError::MergeOverwrite { x: cx, y })?
+                }
+            }
+            spans.push(span);
+            x += span.columns;
+        }
+        v.push(spans);
+    }
+    Ok(v)
+}
+
+/// Validate the table, and return how many columns it has.
+pub fn validate(table: &Table) -> Result<usize, TableError> {
+    let mut rows = table.get_table().iter();
+    if let Some(first) = rows.next() {
+        let e = first.len();
+        for (index, row) in rows.enumerate() {
+            if row.len() != e {
+                return Err(TableError::InvalidTableSize {
+                    row: index + 1,
+                    expected: e,
+                    actual: row.len(),
+                });
+            }
+        }
+        Ok(e)
+    } else {
+        Ok(0)
+    }
+}
+
+/// The alignment of each column.
+///
+/// This is taken from the first cell of the column which is not a header, a splitter or a merged cell,
+/// where the header is everything above the first splitter row.
+pub fn column_aligns(table: &Table, columns: usize) -> Vec<AlignKind> {
+    let rows = table.get_table();
+    let body = rows
+        .iter()
+        .position(|r| is_splitter_row(r))
+        .map_or(0, |h| h + 1);
+    (0..columns)
+        .map(|x| first_align(rows[body..].iter().chain(rows[..body].iter()), x).unwrap_or(AlignKind::Left))
+        .collect()
+}
+
+/// The alignment of the first cell in column `x` of the rows which is not a splitter or a merged cell.
+pub fn first_align<'a>(rows: impl Iterator<Item = &'a Vec<Cell>>, x: usize) -> Option<AlignKind> {
+    rows.filter_map(|row| row.get(x))
+        .find(|c| c.get_merge().unwrap_or(0) == 0 && matches!(c.get_content().kind(), ContentKind::Text(_)))
+        .map(|c| c.get_align().kind())
+}
@@ -0,0 +1,141 @@
+//! Render a table as an Emacs Org table.
+//!
+//! Splitter rows become `|---+---|` rules. Org tables have no merged cells, so the text of a cell with a `merge` or
+//! a `row_span` is put in its first column and row, and the cells it covers are left blank.
+//! Line breaks become spaces, `|` in text is written as `\vert{}`, and formatters are not rendered, nor are ANSI escape
+//! sequences.
+
+use std::io;
+
+use itertools::Itertools;
+
+use crate::{
+    errors::TableError,
+    render::{is_splitter_row, layout, lines, strip_escapes, validate, width},
+    settings::AlignKind,
+    table::{Cell, ContentKind, Table},
+};
+
+pub fn render(table: &Table, writer: &mut impl io::Write) -> Result<(), TableError> {
+    let w = validate(table)?;
+    let layout = layout(table)?;
+    let rows = table.get_table();
+    if w == 0 {
+        return Ok(());
+    }
+    // The text of every cell, with its alignment.
+    let cells = layout
+        .iter()
+        .enumerate()
+        .map(|(y, spans)| {
+            let mut cells = vec![(String::new(), AlignKind::Left); w];
+            for span in spans.iter().filter(|s| s.y == y) {
+                cells[span.x] = (text(span.cell), span.cell.get_align().kind());
+            }
+            cells
+        })
+        .collect_vec();
+    let widths = (0..w)
+        .map(|x| cells.iter().map(|row| width(&row[x].0)).max().unwrap_or(0).max(1))
+        .collect_vec();
+    for (row, cells) in rows.iter().zip(&cells) {
+        if is_splitter_row(row) {
+            writeln!(writer, "|{}|", widths.iter().map(|w| "-".repeat(w + 2)).join("+"))?;
+            continue;
+        }
+        let line = cells
+            .iter()
+            .zip(&widths)
+            .map(|((text, align), w)| {
+                let pad = w - width(text);
+                let left = match align {
+                    AlignKind::Left => 0,
+                    AlignKind::Center => pad / 2,
+                    AlignKind::Right => pad,
+                };
+                format!(" {}{text}{} ", " ".repeat(left), " ".repeat(pad - left))
+            })
+            .join("|");
+        writeln!(writer, "|{line}|")?;
+    }
+    Ok(())
+}
+
+fn text(cell: &Cell) -> String {
+    match cell.get_content().kind() {
+        ContentKind::Text(ref text) => lines(&strip_escapes(text)).map(|l| l.replace('|', "\\vert{}")).join(" "),
+        ContentKind::Splitter | ContentKind::None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{settings::Align, table::Content};
+
+    fn text(s: &str) -> Cell {
+        Cell::default().native_with_content(Content::new(ContentKind::Text(s.to_string())))
+    }
+
+    fn splitter() -> Cell {
+        Cell::default().native_with_content(Content::Splitter)
+    }
+
+    fn rendered(table: &Table) -> String {
+        let mut v = Vec::new();
+        render(table, &mut v).unwrap();
+        String::from_utf8(v).unwrap()
+    }
+
+    #[test]
+    fn plain_table() {
+        let table = Table::new(vec![
+            vec![text("name"), text("size")],
+            vec![splitter(), splitter()],
+            vec![text("a|b"), text("1").native_with_align(Align::Right)],
+            vec![text("c\nd"), text("22").native_with_align(Align::Center)],
+        ]);
+        assert_eq!(
+            rendered(&table),
+            "| name      | size |\n|-----------+------|\n| a\\vert{}b |    1 |\n| c d       |  22  |\n"
+        );
+        assert_eq!(rendered(&Table::new(vec![vec![], vec![]])), "");
+    }
+
+    #[test]
+    fn merged_cells_are_left_blank() {
+        let table = Table::new(vec![
+            vec![text("head").native_with_merge(Some(1)), Cell::default(), text("c")],
+            vec![splitter(); 3],
+            vec![text("a"), text("wide cell").native_with_merge(Some(1)), Cell::default()],
+            vec![text("x"), text("y"), text("z")],
+        ]);
+        assert_eq!(
+            rendered(&table),
+            "| head |           | c |\n|------+-----------+---|\n| a    | wide cell |   |\n| x    | y         | z |\n"
+        );
+        let table = Table::new(vec![
+            vec![text("tall").native_with_row_span(Some(1)), text("x"), text("1")],
+            vec![Cell::default(), text("y"), text("2")],
+            vec![text("both").native_with_row_span(Some(1)).native_with_merge(Some(1)), Cell::default(), text("3")],
+            vec![Cell::default(), Cell::default(), text("4")],
+        ]);
+        assert_eq!(
+            rendered(&table),
+            "| tall | x | 1 |\n|      | y | 2 |\n| both |   | 3 |\n|      |   | 4 |\n"
+        );
+    }
+
+    #[test]
+    fn splitter_rows_are_rules() {
+        // Even the one covered by a `row_span`.
+        let table = Table::new(vec![
+            vec![text("a"), text("b")],
+            vec![splitter(), splitter()],
+            vec![text("e").native_with_row_span(Some(2)), text("f")],
+            vec![Cell::default(), splitter()],
+            vec![Cell::default(), text("g")],
+        ]);
+        assert_eq!(rendered(&table), "| a | b |\n|---+---|\n| e | f |\n|---+---|\n|   | g |\n");
+    }
+}
@@ -0,0 +1,475 @@
+//! Render a table as a reStructuredText grid table or simple table.
+//!
+//! Rows above the first splitter row are the header, which ends with a `===` rule. Other splitter rows are dropped,
+//! since every row of a grid table is ruled anyway, and a simple table has no rules in its body.
+//! Formatters are not rendered, and text is written as it is apart from its ANSI escape sequences, so it may contain
+//! inline markup.
+
+use std::io;
+
+use itertools::Itertools;
+
+use crate::{
+    errors::TableError,
+    render::{is_splitter_row, layout, lines, strip_escapes, validate, width, Span},
+    table::{Cell, ContentKind, Table},
+};
+
+/// Render a grid table, which can express both `merge` and `row_span`.
+///
+/// A `row_span` must not cross the header rule, which has to run across the whole table.
+pub fn render_grid(table: &Table, writer: &mut impl io::Write) -> Result<(), TableError> {
+    let measure = |span: &Span| {
+        cell_lines(span.cell)
+            .iter()
+            .map(|l| width(l))
+            .max()
+            .unwrap_or(0)
+    };
+    let grid = match Grid::new(table, 3, measure)? {
+        Some(grid) => grid,
+        None => return Ok(()),
+    };
+    if let Some((_, _, span)) = grid.blocks().find(|&(a, b, _)| a < grid.header && grid.header <= b) {
+        return Err(TableError::SpanOverHeader { x: span.x, y: span.y });
+    }
+    let n = grid.visible.len();
+    // A block of `row_span` takes the lines of every row it covers, and of the rules between them.
+    let mut heights = vec![1; n];
+    for (a, b, span) in grid
+        .blocks()
+        .sorted_by_key(|(a, b, span)| (b - a, span.columns))
+    {
+        let need = cell_lines(span.cell).len();
+        let have = heights[a..=b].iter().sum::<usize>() + (b - a);
+        if need > have {
+            heights[b] += need - have;
+        }
+    }
+    let offset = |a: usize, i: usize| heights[a..i].iter().sum::<usize>() + (i - a);
+    for (i, &height) in heights.iter().enumerate() {
+        let c = if i > 0 && i == grid.header { '=' } else { '-' };
+        writeln!(writer, "{}", grid.rule(i, c, |a| offset(a, i) - 1))?;
+        for l in 0..height {
+            let line = grid.layout[grid.visible[i]]
+                .iter()
+                .map(|span| grid.segment(span, offset(grid.index(span.y), i) + l))
+                .join("|");
+            writeln!(writer, "|{line}|")?;
+        }
+    }
+    writeln!(writer, "{}", grid.rule(n, '-', |a| offset(a, n) - 1))?;
+    Ok(())
+}
+
+/// Render a simple table.
+///
+/// Merged cells are marked by a `---` underline below their row, or by the `===` border below it, but a simple table has no row spans,
+/// so the rows covered by a `row_span` are left blank. Text in the first column is joined into one line,
+/// and a blank first cell is written as `\`, since a line starting with blanks continues the row above.
+pub fn render_simple(table: &Table, writer: &mut impl io::Write) -> Result<(), TableError> {
+    let measure = |span: &Span| {
+        simple_lines(span.cell, span.x)
+            .iter()
+            .map(|l| width(l))
+            .max()
+            .unwrap_or(0)
+    };
+    let grid = match Grid::new(table, 2, measure)? {
+        Some(grid) => grid,
+        None => return Ok(()),
+    };
+    let n = grid.visible.len();
+    let border = grid.widths.iter().map(|w| "=".repeat(*w)).join("  ");
+    writeln!(writer, "{border}")?;
+    for (i, &y) in grid.visible.iter().enumerate() {
+        let spans = &grid.layout[y];
+        let cells = spans
+            .iter()
+            .map(|span| {
+                if grid.index(span.y) < i {
+                    simple_lines(&Cell::default(), span.x)
+                } else {
+                    simple_lines(span.cell, span.x)
+                }
+            })
+            .collect_vec();
+        let height = cells.iter().map(|c| c.len()).max().unwrap_or(1);
+        for l in 0..height {
+            let line = spans
+                .iter()
+                .zip(&cells)
+                .map(|(span, lines)| {
+                    let text = lines.get(l).map_or("", |s| s.as_str());
+                    let pad = grid.span_width(span).saturating_sub(width(text));
+                    format!("{text}{}", " ".repeat(pad))
+                })
+                .join("  ");
+            writeln!(writer, "{}", line.trim_end())?;
+        }
+        // A border below the row underlines its merged cells itself, since an underline right above a border
+        // would be read as an empty row.
+        let ruled = i + 1 == grid.header || i + 1 == n;
+        if ruled || spans.iter().any(|s| s.columns > 1) {
+            let c = if ruled { "=" } else { "-" };
+            let underline = spans
+                .iter()
+                .map(|s| c.repeat(grid.span_width(s)))
+                .join("  ");
+            writeln!(writer, "{underline}")?;
+        }
+    }
+    Ok(())
+}
+
+struct Grid<'a> {
+    layout: Vec<Vec<Span<'a>>>,
+    /// Rows which are not splitter rows.
+    visible: Vec<usize>,
+    /// Number of visible rows in the header.
+    header: usize,
+    widths: Vec<usize>,
+    /// Width of the gap between two columns.
+    gap: usize,
+}
+
+impl<'a> Grid<'a> {
+    /// Returns `None` if the table has nothing to render, since reStructuredText has no empty tables.
+    /// `measure` is the width a cell needs.
+    fn new(
+        table: &'a Table,
+        gap: usize,
+        measure: impl Fn(&Span) -> usize,
+    ) -> Result<Option<Grid<'a>>, TableError> {
+        let w = validate(table)?;
+        let layout = layout(table)?;
+        let rows = table.get_table();
+        let visible = (0..rows.len())
+            .filter(|&y| !is_splitter_row(&rows[y]))
+            .collect_vec();
+        if w == 0 || visible.is_empty() {
+            return Ok(None);
+        }
+        let header = rows
+            .iter()
+            .position(|r| is_splitter_row(r))
+            .map_or(0, |p| visible.partition_point(|&y| y < p));
+        let mut grid = Grid {
+            layout,
+            visible,
+            header,
+            widths: vec![1; w],
+            gap,
+        };
+        // Merged cells widen their last column if the columns below them are too narrow.
+        let spans = grid
+            .blocks()
+            .map(|(_, _, span)| span)
+            .sorted_by_key(|span| span.columns)
+            .collect_vec();
+        for span in spans {
+            let need = measure(&span);
+            let have = grid.span_width(&span);
+            if need > have {
+                grid.widths[span.x + span.columns - 1] += need - have;
+            }
+        }
+        Ok(Some(grid))
+    }
+
+    /// The visible row which a row is rendered in, which is the next one for splitter rows.
+    fn index(&self, y: usize) -> usize {
+        self.visible.partition_point(|&v| v < y)
+    }
+
+    /// Every visible cell once, with the first and the last visible rows it covers.
+    fn blocks(&self) -> impl Iterator<Item = (usize, usize, Span<'a>)> + '_ {
+        self.visible.iter().enumerate().flat_map(move |(i, &y)| {
+            self.layout[y]
+                .iter()
+                .filter(move |s| self.index(s.y) == i)
+                .map(move |s| (i, self.index(s.y + s.rows) - 1, *s))
+        })
+    }
+
+    fn span_width(&self, span: &Span) -> usize {
+        self.widths[span.x..span.x + span.columns]
+            .iter()
+            .sum::<usize>()
+            + self.gap * (span.columns - 1)
+    }
+
+    /// A line of a cell, padded to the width of the span.
+    fn segment(&self, span: &Span, line: usize) -> String {
+        let lines = cell_lines(span.cell);
+        let text = lines.get(line).map_or("", |s| s.as_str());
+        let pad = self.span_width(span).saturating_sub(width(text));
+        format!(" {text}{} ", " ".repeat(pad))
+    }
+
+    /// The rule above the visible row `i`, or the bottom border if `i` is past the last row.
+    ///
+    /// A cell with a `row_span` crossing the rule shows its line at `line(first row of the cell)` instead.
+    fn rule(&self, i: usize, c: char, line: impl Fn(usize) -> usize) -> String {
+        let w = self.widths.len();
+        // Column boundaries which have a vertical line above or below the rule.
+        let mut vline = vec![false; w + 1];
+        for y in [i.checked_sub(1), Some(i)].into_iter().flatten() {
+            for s in self.visible.get(y).map_or(&[][..], |&y| &self.layout[y]) {
+                vline[s.x] = true;
+                vline[s.x + s.columns] = true;
+            }
+        }
+        // Each column under the rule, or a cell crossing it.
+        let segments: Vec<(usize, Option<&Span>)> = match self.visible.get(i) {
+            Some(&y) => self.layout[y]
+                .iter()
+                .flat_map(|s| {
+                    if self.index(s.y) < i {
+                        vec![(s.x, Some(s))]
+                    } else {
+                        (s.x..s.x + s.columns).map(|x| (x, None)).collect()
+                    }
+                })
+                .collect(),
+            None => (0..w).map(|x| (x, None)).collect(),
+        };
+        let mut out = String::new();
+        let mut previous: Option<bool> = None;
+        for (x, span) in segments {
+            let ruled = span.is_none();
+            out.push(match (previous, ruled) {
+                (Some(true), true) if !vline[x] => c,
+                (Some(false) | None, false) => '|',
+                _ => '+',
+            });
+            match span {
+                Some(span) => out.push_str(&self.segment(span, line(self.index(span.y)))),
+                None => out.extend(std::iter::repeat_n(c, self.widths[x] + 2)),
+            }
+            previous = Some(ruled);
+        }
+        out.push(if previous == Some(false) { '|' } else { '+' });
+        out
+    }
+}
+
+fn cell_lines(cell: &Cell) -> Vec<String> {
+    match cell.get_content().kind() {
+        ContentKind::Text(ref text) => lines(&strip_escapes(text)).map(str::to_string).collect(),
+        ContentKind::Splitter | ContentKind::None => Vec::new(),
+    }
+}
+
+/// The lines of a cell in a simple table, where a cell in the first column takes one line, which must not be blank.
+fn simple_lines(cell: &Cell, x: usize) -> Vec<String> {
+    let lines = cell_lines(cell);
+    if x == 0 {
+        let first = lines
+            .iter()
+            .map(|l| l.trim())
+            .filter(|l| !l.is_empty())
+            .join(" ");
+        vec![if first.is_empty() {
+            "\\".to_string()
+        } else {
+            first
+        }]
+    } else {
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::Content;
+
+    fn text(s: &str) -> Cell {
+        Cell::default().native_with_content(Content::new(ContentKind::Text(s.to_string())))
+    }
+
+    fn grid(table: &Table) -> String {
+        let mut v = Vec::new();
+        render_grid(table, &mut v).unwrap();
+        String::from_utf8(v).unwrap()
+    }
+
+    #[test]
+    fn row_span_across_header_is_an_error() {
+        let table = Table::new(vec![
+            vec![text("a").native_with_row_span(Some(2)), text("h")],
+            vec![Cell::default(), splitter()],
+            vec![Cell::default(), text("x")],
+        ]);
+        let err = render_grid(&table, &mut Vec::new()).unwrap_err();
+        assert!(matches!(err, TableError::SpanOverHeader { x: 0, y: 0 }));
+        // The same span is fine in the body, and in a simple table, which leaves the covered rows blank.
+        let table = Table::new(vec![
+            vec![text("h"), text("i")],
+            vec![splitter(); 2],
+            vec![text("a").native_with_row_span(Some(1)), text("x")],
+            vec![Cell::default(), text("y")],
+        ]);
+        assert_eq!(
+            grid(&table),
+            "+---+---+\n| h | i |\n+===+===+\n| a | x |\n|   +---+\n|   | y |\n+---+---+\n"
+        );
+    }
+
+    fn splitter() -> Cell {
+        Cell::default().native_with_content(Content::Splitter)
+    }
+
+    /// A header of `h1` and `h2`, with a multi-line cell with a `row_span` below it.
+    fn row_span_table() -> Table {
+        Table::new(vec![
+            vec![text("h1"), text("h2")],
+            vec![splitter(), splitter()],
+            vec![text("tall\ncell\nhere").native_with_row_span(Some(1)), text("x")],
+            vec![Cell::default(), text("y")],
+            vec![text(""), text("z")],
+        ])
+    }
+
+    /// Splitter rows in the body, one of them covered by a `row_span`.
+    fn splitters_table() -> Table {
+        Table::new(vec![
+            vec![text("a"), text("b")],
+            vec![text("c"), text("d")],
+            vec![splitter(), splitter()],
+            vec![text("e").native_with_row_span(Some(2)), text("f")],
+            vec![Cell::default(), splitter()],
+            vec![Cell::default(), text("g")],
+        ])
+    }
+
+    #[test]
+    fn grid_table() {
+        let table = Table::new(vec![
+            vec![text("name"), text("size")],
+            vec![splitter(), splitter()],
+            vec![text("a"), text("1")],
+            vec![text("bb\nb"), text("22")],
+        ]);
+        assert_eq!(
+            grid(&table),
+            "+------+------+\n\
+             | name | size |\n\
+             +======+======+\n\
+             | a    | 1    |\n\
+             +------+------+\n\
+             | bb   | 22   |\n\
+             | b    |      |\n\
+             +------+------+\n"
+        );
+        assert_eq!(rendered_empty(render_grid), "");
+    }
+
+    #[test]
+    fn grid_table_merge() {
+        let table = Table::new(vec![
+            vec![text("head").native_with_merge(Some(1)), Cell::default(), text("c")],
+            vec![splitter(); 3],
+            vec![text("a"), text("wide cell").native_with_merge(Some(1)), Cell::default()],
+            vec![text("x"), text("y"), text("z")],
+        ]);
+        assert_eq!(
+            grid(&table),
+            "+-------+-------+\n\
+             | head  | c     |\n\
+             +===+===+=======+\n\
+             | a | wide cell |\n\
+             +---+---+-------+\n\
+             | x | y | z     |\n\
+             +---+---+-------+\n"
+        );
+    }
+
+    #[test]
+    fn grid_table_row_span() {
+        // The cell takes the lines of both rows, and of the rule between them.
+        assert_eq!(
+            grid(&row_span_table()),
+            "+------+----+\n\
+             | h1   | h2 |\n\
+             +======+====+\n\
+             | tall | x  |\n\
+             | cell +----+\n\
+             | here | y  |\n\
+             +------+----+\n\
+             |      | z  |\n\
+             +------+----+\n"
+        );
+        // Splitter rows after the first one are dropped, even under a `row_span`.
+        assert_eq!(
+            grid(&splitters_table()),
+            "+---+---+\n\
+             | a | b |\n\
+             +---+---+\n\
+             | c | d |\n\
+             +===+===+\n\
+             | e | f |\n\
+             |   +---+\n\
+             |   | g |\n\
+             +---+---+\n"
+        );
+    }
+
+    #[test]
+    fn simple_table() {
+        let table = Table::new(vec![
+            vec![text("name"), text("size")],
+            vec![splitter(), splitter()],
+            vec![text("a"), text("1")],
+            vec![text("bb\nb"), text("2\n22")],
+        ]);
+        // Text in the first column is joined into one line.
+        assert_eq!(simple(&table), "====  ====\nname  size\n====  ====\na     1\nbb b  2\n      22\n====  ====\n");
+        assert_eq!(rendered_empty(render_simple), "");
+    }
+
+    #[test]
+    fn simple_table_row_span() {
+        // A blank first cell is written as `\`, so it does not continue the row above.
+        assert_eq!(
+            simple(&row_span_table()),
+            "==============  ==\n\
+             h1              h2\n\
+             ==============  ==\n\
+             tall cell here  x\n\
+             \\               y\n\
+             \\               z\n\
+             ==============  ==\n"
+        );
+        assert_eq!(simple(&splitters_table()), "=  =\na  b\nc  d\n=  =\ne  f\n\\  g\n=  =\n");
+    }
+
+    fn rendered_empty(render: fn(&Table, &mut Vec<u8>) -> Result<(), TableError>) -> String {
+        let mut v = Vec::new();
+        render(&Table::new(vec![]), &mut v).unwrap();
+        render(&Table::new(vec![vec![], vec![]]), &mut v).unwrap();
+        String::from_utf8(v).unwrap()
+    }
+
+    fn simple(table: &Table) -> String {
+        let mut v = Vec::new();
+        render_simple(table, &mut v).unwrap();
+        String::from_utf8(v).unwrap()
+    }
+
+    #[test]
+    fn borders_underline_merged_cells() {
+        let table = Table::new(vec![
+            vec![text("head").native_with_merge(Some(1)), Cell::default(), text("c")],
+            vec![splitter(); 3],
+            vec![text("a"), text("wide cell").native_with_merge(Some(1)), Cell::default()],
+            vec![text("x"), text("y"), text("z")],
+            vec![text("last").native_with_merge(Some(1)), Cell::default(), text("row")],
+        ]);
+        assert_eq!(
+            simple(&table),
+            "=  =  ======\nhead  c\n====  ======\na  wide cell\n-  ---------\nx  y  z\nlast  row\n====  ======\n"
+        );
+    }
+}
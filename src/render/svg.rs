@@ -0,0 +1,382 @@
+//! Render a table as an SVG image of what `Renderer.Normal` prints in a terminal.
+//!
+//! The table is rendered by `Renderer.Normal`, and every character takes a cell of a monospace grid, which is
+//! `0.6` em wide and `1.2` em high, or two cells for East Asian wide characters. Text is drawn with the styles of the
+//! formatters, and box drawing characters of the borders and splitters become lines, so they join without gaps.
+
+use std::io;
+
+use itertools::Itertools;
+
+use crate::{
+    errors::TableError,
+    render::{ansi, graphemes, html::escape},
+    settings::Style,
+    table::{Column, Table},
+};
+
+const LEFT: u8 = 1;
+const RIGHT: u8 = 2;
+const UP: u8 = 4;
+const DOWN: u8 = 8;
+
+type Rgb = (u8, u8, u8);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Weight {
+    Light,
+    Heavy,
+    Double,
+}
+
+/// A line in half cells, so the center of the cell at `(x, y)` is `(2x + 1, 2y + 1)`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Line {
+    x1: usize,
+    y1: usize,
+    x2: usize,
+    y2: usize,
+    weight: Weight,
+    color: Option<Rgb>,
+}
+
+/// `columns` are the columns of the table before they were applied to its cells.
+pub fn render(
+    table: &Table,
+    writer: &mut impl io::Write,
+    columns: &[Column],
+    font_family: &str,
+    font_size: f64,
+) -> Result<(), TableError> {
+    let output = ansi::render_normal(table, columns)?;
+    let mut rows = ansi::styled(&output);
+    if rows.last().is_some_and(|r| r.is_empty()) {
+        rows.pop();
+    }
+    let cell_width = font_size * 0.6;
+    let cell_height = font_size * 1.2;
+    let margin = font_size / 2.0;
+    let x_at = |x: usize| margin + x as f64 * cell_width;
+    let y_at = |y: usize| margin + y as f64 * cell_height;
+
+    let mut backgrounds = Vec::new();
+    let mut texts = Vec::new();
+    let mut lines: Vec<Line> = Vec::new();
+    let mut columns = 0;
+    for (y, runs) in rows.iter().enumerate() {
+        let mut x = 0;
+        for &(style, s) in runs {
+            let (foreground, background) = colors(&style);
+            // Text between box drawing characters, with the column it starts at and its width.
+            let mut text = (x, 0, String::new());
+            let mut flush = |text: &mut (usize, usize, String)| {
+                let (start, width, s) = std::mem::take(text);
+                if width == 0 {
+                    return;
+                }
+                if let Some((r, g, b)) = background {
+                    backgrounds.push(format!(
+                        "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"#{r:02x}{g:02x}{b:02x}\"/>",
+                        num(x_at(start)),
+                        num(y_at(y)),
+                        num(width as f64 * cell_width),
+                        num(cell_height),
+                    ));
+                }
+                if !style.hidden && (!s.trim().is_empty() || style.underline || style.strikethrough) {
+                    texts.push(format!(
+                        "<text x=\"{}\" y=\"{}\" textLength=\"{}\"{}>{}</text>",
+                        num(x_at(start)),
+                        num(y_at(y) + cell_height / 2.0),
+                        num(width as f64 * cell_width),
+                        attributes(&style, foreground),
+                        escape(&s),
+                    ));
+                }
+            };
+            for (g, width) in graphemes(s) {
+                match arms(g) {
+                    Some((arms, weight)) => {
+                        flush(&mut text);
+                        let (cx, cy) = (2 * x + 1, 2 * y + 1);
+                        let mut push = |x1, y1, x2, y2| {
+                            lines.push(Line { x1, y1, x2, y2, weight, color: foreground });
+                        };
+                        if arms & LEFT != 0 {
+                            push(cx - 1, cy, cx, cy);
+                        }
+                        if arms & RIGHT != 0 {
+                            push(cx, cy, cx + 1, cy);
+                        }
+                        if arms & UP != 0 {
+                            push(cx, cy - 1, cx, cy);
+                        }
+                        if arms & DOWN != 0 {
+                            push(cx, cy, cx, cy + 1);
+                        }
+                        text.0 = x + width;
+                    }
+                    None => {
+                        text.1 += width;
+                        text.2.push_str(g);
+                    }
+                }
+                x += width;
+            }
+            flush(&mut text);
+        }
+        columns = columns.max(x);
+    }
+
+    let width = 2.0 * margin + columns as f64 * cell_width;
+    let height = 2.0 * margin + rows.len() as f64 * cell_height;
+    writeln!(
+        writer,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" font-family=\"{}\" font-size=\"{}\" xml:space=\"preserve\">",
+        escape(font_family),
+        num(font_size),
+        w = num(width),
+        h = num(height),
+    )?;
+    for rect in backgrounds {
+        writeln!(writer, "  {rect}")?;
+    }
+    let stroke = font_size / 14.0;
+    writeln!(writer, "  <g stroke=\"currentColor\" stroke-width=\"{}\" stroke-linecap=\"square\">", num(stroke))?;
+    for line in merge(lines) {
+        let point = |x: usize, y: usize| (margin + x as f64 * cell_width / 2.0, margin + y as f64 * cell_height / 2.0);
+        let (x1, y1) = point(line.x1, line.y1);
+        let (x2, y2) = point(line.x2, line.y2);
+        let color = line.color.map_or(String::new(), |(r, g, b)| format!(" stroke=\"#{r:02x}{g:02x}{b:02x}\""));
+        let (offsets, weight): (&[f64], _) = match line.weight {
+            Weight::Light => (&[0.0], String::new()),
+            Weight::Heavy => (&[0.0], format!(" stroke-width=\"{}\"", num(stroke * 2.0))),
+            Weight::Double => (&[-font_size / 8.0, font_size / 8.0], String::new()),
+        };
+        for offset in offsets {
+            // Double lines are drawn as two lines on each side of the center.
+            let (dx, dy) = if y1 == y2 { (0.0, *offset) } else { (*offset, 0.0) };
+            writeln!(
+                writer,
+                "    <line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\"{color}{weight}/>",
+                num(x1 + dx),
+                num(y1 + dy),
+                num(x2 + dx),
+                num(y2 + dy),
+            )?;
+        }
+    }
+    writeln!(writer, "  </g>")?;
+    writeln!(writer, "  <g dominant-baseline=\"central\">")?;
+    for text in texts {
+        writeln!(writer, "    {text}")?;
+    }
+    writeln!(writer, "  </g>")?;
+    writeln!(writer, "</svg>")?;
+    Ok(())
+}
+
+/// The colors of text and of its background, which are swapped by `reversed`.
+fn colors(style: &Style) -> (Option<Rgb>, Option<Rgb>) {
+    if style.reversed {
+        (
+            Some(style.background.unwrap_or((255, 255, 255))),
+            Some(style.foreground.unwrap_or((0, 0, 0))),
+        )
+    } else {
+        (style.foreground, style.background)
+    }
+}
+
+fn attributes(style: &Style, foreground: Option<Rgb>) -> String {
+    let mut v = Vec::new();
+    if let Some((r, g, b)) = foreground {
+        v.push(format!("fill=\"#{r:02x}{g:02x}{b:02x}\""));
+    }
+    if style.bold {
+        v.push("font-weight=\"bold\"".to_string());
+    }
+    if style.italic {
+        v.push("font-style=\"italic\"".to_string());
+    }
+    if style.dimmed {
+        v.push("opacity=\"0.5\"".to_string());
+    }
+    let decoration = [(style.underline, "underline"), (style.strikethrough, "line-through")]
+        .iter()
+        .filter(|(flag, _)| *flag)
+        .map(|(_, d)| *d)
+        .join(" ");
+    if !decoration.is_empty() {
+        v.push(format!("text-decoration=\"{decoration}\""));
+    }
+    v.iter().map(|a| format!(" {a}")).collect()
+}
+
+/// Which arms of a box drawing character are drawn, and how.
+fn arms(g: &str) -> Option<(u8, Weight)> {
+    const ARMS: [u8; 11] = [
+        LEFT | RIGHT,
+        UP | DOWN,
+        RIGHT | DOWN,
+        LEFT | DOWN,
+        RIGHT | UP,
+        LEFT | UP,
+        LEFT | RIGHT | DOWN,
+        LEFT | RIGHT | UP,
+        RIGHT | UP | DOWN,
+        LEFT | UP | DOWN,
+        LEFT | RIGHT | UP | DOWN,
+    ];
+    let mut chars = g.chars();
+    let c = chars.next()?;
+    if chars.next().is_some() {
+        return None;
+    }
+    // The characters in the order of `ARMS`, from the index they start at.
+    [
+        ("─│┌┐└┘┬┴├┤┼", 0, Weight::Light),
+        ("╭╮╰╯", 2, Weight::Light),
+        ("━┃┏┓┗┛┳┻┣┫╋", 0, Weight::Heavy),
+        ("═║╔╗╚╝╦╩╠╣╬", 0, Weight::Double),
+    ]
+    .iter()
+    .find_map(|(chars, start, weight)| chars.chars().position(|x| x == c).map(|i| (ARMS[start + i], *weight)))
+}
+
+/// Join lines which continue each other into one.
+fn merge(mut lines: Vec<Line>) -> Vec<Line> {
+    // Horizontal lines first, by row, then vertical lines by column.
+    lines.sort_by_key(|l| if l.y1 == l.y2 { (0, l.y1, l.x1) } else { (1, l.x1, l.y1) });
+    let mut v: Vec<Line> = Vec::new();
+    for line in lines {
+        match v.last_mut() {
+            Some(last)
+                if (last.weight, last.color) == (line.weight, line.color)
+                    && ((last.y1 == last.y2 && line.y1 == line.y2 && last.y1 == line.y1 && last.x2 == line.x1)
+                        || (last.x1 == last.x2 && line.x1 == line.x2 && last.x1 == line.x1 && last.y2 == line.y1)) =>
+            {
+                last.x2 = line.x2;
+                last.y2 = line.y2;
+            }
+            _ => v.push(line),
+        }
+    }
+    v
+}
+
+/// Format a number without trailing zeros.
+fn num(v: f64) -> String {
+    let s = format!("{v:.2}");
+    s.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        settings::{Border, BorderStyle, Formatter},
+        table::{Cell, Content, ContentKind},
+    };
+
+    fn text(s: &str) -> Cell {
+        Cell::default().native_with_content(Content::new(ContentKind::Text(s.to_string())))
+    }
+
+    fn rendered(table: &Table) -> String {
+        let mut v = Vec::new();
+        render(table, &mut v, &[], "mono", 10.0).unwrap();
+        String::from_utf8(v).unwrap()
+    }
+
+    /// The elements of the svg with the tag, trimmed.
+    fn elements<'a>(svg: &'a str, tag: &str) -> Vec<&'a str> {
+        svg.lines().map(str::trim).filter(|l| l.starts_with(tag)).collect()
+    }
+
+    #[test]
+    fn formatters_style_text() {
+        let table = Table::new(vec![vec![
+            text("a<").native_with_formatter(vec![Formatter::rbg_color(255, 0, 0), Formatter::Bold]),
+            text("b").native_with_formatter(vec![Formatter::on_rbg_color(0, 0, 255), Formatter::Underline, Formatter::Italic]),
+            text("c").native_with_formatter(vec![Formatter::Reversed, Formatter::Dimmed]),
+        ]]);
+        // Every character takes 6 by 12, with a margin of 5 around the table.
+        assert_eq!(
+            rendered(&table).lines().collect::<Vec<_>>(),
+            [
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"70\" height=\"22\" viewBox=\"0 0 70 22\" \
+                 font-family=\"mono\" font-size=\"10\" xml:space=\"preserve\">",
+                "  <rect x=\"29\" y=\"5\" width=\"18\" height=\"12\" fill=\"#0000ff\"/>",
+                "  <rect x=\"47\" y=\"5\" width=\"18\" height=\"12\" fill=\"#000000\"/>",
+                "  <g stroke=\"currentColor\" stroke-width=\"0.71\" stroke-linecap=\"square\">",
+                "  </g>",
+                "  <g dominant-baseline=\"central\">",
+                "    <text x=\"5\" y=\"11\" textLength=\"24\" fill=\"#ff0000\" font-weight=\"bold\"> a&lt; </text>",
+                "    <text x=\"29\" y=\"11\" textLength=\"18\" font-style=\"italic\" text-decoration=\"underline\"> b </text>",
+                "    <text x=\"47\" y=\"11\" textLength=\"18\" fill=\"#ffffff\" opacity=\"0.5\"> c </text>",
+                "  </g>",
+                "</svg>",
+            ]
+        );
+    }
+
+    #[test]
+    fn heavy_borders_are_wider_lines() {
+        let splitter = Cell::default().native_with_content(Content::Splitter);
+        let table = Table::new(vec![
+            vec![text("a"), text("b")],
+            vec![splitter.clone(), splitter],
+            vec![text("c").native_with_merge(Some(1)), Cell::default()],
+        ])
+        .native_with_border(Border::All.native_with_style(BorderStyle::native_from_name("heavy").unwrap()))
+        .native_with_column_separator(true);
+        // The splitter joins the borders, and the separator stops above the merged cell.
+        assert_eq!(
+            elements(&rendered(&table), "<line"),
+            [
+                "<line x1=\"8\" y1=\"11\" x2=\"56\" y2=\"11\" stroke-width=\"1.43\"/>",
+                "<line x1=\"8\" y1=\"35\" x2=\"56\" y2=\"35\" stroke-width=\"1.43\"/>",
+                "<line x1=\"8\" y1=\"59\" x2=\"56\" y2=\"59\" stroke-width=\"1.43\"/>",
+                "<line x1=\"8\" y1=\"11\" x2=\"8\" y2=\"59\" stroke-width=\"1.43\"/>",
+                "<line x1=\"32\" y1=\"11\" x2=\"32\" y2=\"35\" stroke-width=\"1.43\"/>",
+                "<line x1=\"56\" y1=\"11\" x2=\"56\" y2=\"59\" stroke-width=\"1.43\"/>",
+            ]
+        );
+    }
+
+    #[test]
+    fn double_borders_are_two_lines() {
+        let table = Table::new(vec![
+            vec![text("a").native_with_row_span(Some(1)), text("b")],
+            vec![Cell::default(), text("中")],
+        ])
+        .native_with_border(Border::All.native_with_style(BorderStyle::native_from_name("double").unwrap()))
+        .native_with_column_separator(true);
+        let svg = rendered(&table);
+        assert_eq!(
+            elements(&svg, "<line"),
+            [
+                "<line x1=\"8\" y1=\"9.75\" x2=\"62\" y2=\"9.75\"/>",
+                "<line x1=\"8\" y1=\"12.25\" x2=\"62\" y2=\"12.25\"/>",
+                "<line x1=\"8\" y1=\"45.75\" x2=\"62\" y2=\"45.75\"/>",
+                "<line x1=\"8\" y1=\"48.25\" x2=\"62\" y2=\"48.25\"/>",
+                "<line x1=\"6.75\" y1=\"11\" x2=\"6.75\" y2=\"47\"/>",
+                "<line x1=\"9.25\" y1=\"11\" x2=\"9.25\" y2=\"47\"/>",
+                "<line x1=\"30.75\" y1=\"11\" x2=\"30.75\" y2=\"47\"/>",
+                "<line x1=\"33.25\" y1=\"11\" x2=\"33.25\" y2=\"47\"/>",
+                "<line x1=\"60.75\" y1=\"11\" x2=\"60.75\" y2=\"47\"/>",
+                "<line x1=\"63.25\" y1=\"11\" x2=\"63.25\" y2=\"47\"/>",
+            ]
+        );
+        // The wide character takes two cells, and the `row_span` leaves no rule between its rows.
+        assert_eq!(
+            elements(&svg, "<text"),
+            [
+                "<text x=\"11\" y=\"23\" textLength=\"18\"> a </text>",
+                "<text x=\"35\" y=\"23\" textLength=\"24\"> b  </text>",
+                "<text x=\"35\" y=\"35\" textLength=\"24\"> 中 </text>",
+            ]
+        );
+    }
+}
@@ -0,0 +1,533 @@
+//! Render a table as plain text, which is used by both `Renderer.Normal` and `Renderer.Raw`.
+//!
+//! The width of a column is the largest `width` of its cells, and every cell is surrounded by a space on each side.
+//! When the table is wider than `Table.max_width`, or than the terminal with `Table.fit_terminal`,
+//! the columns are shrunk by `Table.shrink`.
+//! Lines are drawn with the characters of the table's `BorderStyle`, and `Table.column_separator` adds a vertical line
+//! between columns, except inside merged cells. A cell with a `row_span` continues through the rows it covers,
+//! including splitter rows.
+//!
+//! Content may contain ANSI escape sequences. They take no width, and the styles they leave open are closed at the end
+//! of every line.
+
+use std::{collections::HashMap, io};
+
+use colored::{ColoredString, Colorize};
+
+use crate::{
+    errors::TableError,
+    render::{self, ansi, is_splitter, layout, lines, validate, Span},
+    settings::{AlignKind, BorderStyle, Overflow, Style},
+    table::{Cell, Column, ContentKind, Table},
+};
+
+/// How the formatters of cells, and the bold border, are applied.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Paint {
+    /// Not at all, for `Renderer.Raw`.
+    None,
+    /// By `colored`, which leaves them out when stdout is not a terminal or `NO_COLOR` is set, for `Renderer.Normal`.
+    Terminal,
+    /// By SGR sequences built from the `Style` of the formatters, for exports which keep the styles anywhere.
+    Always,
+}
+
+/// Render the table, with the formatters applied according to `paint`.
+/// `columns` are the columns of the table before they were applied to its cells, whose `min_width` bounds the shrinking.
+pub fn render(table: &Table, writer: &mut impl io::Write, columns: &[Column], paint: Paint) -> Result<(), TableError> {
+    let w = validate(table)?;
+    let border = table.get_border();
+    let style = table.get_border().get_style();
+    let separator = table.get_column_separator();
+    let mut widths = column_widths(table, w);
+    let terminal = if table.get_fit_terminal() { terminal_width() } else { None };
+    if let Some(max) = table.get_max_width().into_iter().chain(terminal).min() {
+        // Everything but the content: the borders, the separators and the space on each side of every cell.
+        let fixed = border.get_left() as usize + border.get_right() as usize + 2 * w + if separator { w.saturating_sub(1) } else { 0 };
+        let floors = columns.iter().map(|c| c.get_min_width().unwrap_or(0)).collect::<Vec<_>>();
+        table.get_shrink().shrink(&mut widths, &floors, max.saturating_sub(fixed));
+    }
+    let bold = |s: String| match paint {
+        Paint::None => s,
+        Paint::Terminal => s.bold().to_string(),
+        Paint::Always => ansi::paint(&s, &Style { bold: true, ..Style::default() }),
+    };
+    let rows = layout(table)?;
+    // Whether a vertical line is drawn on the left of column `x` in row `y`.
+    let line_at = |y: usize, x: usize| {
+        separator && x > 0 && rows.get(y).is_some_and(|row| row.iter().any(|s| s.x == x))
+    };
+    // Splitters are drawn at the first row of their cell.
+    let splitter_at = |y: usize, span: &Span| span.y == y && is_splitter(span.cell);
+
+    let line = |left: char, right: char, junction: char, y: usize| {
+        let mut s = String::new();
+        if border.get_left() {
+            s.push(left);
+        }
+        for (x, width) in widths.iter().enumerate() {
+            if separator && x > 0 {
+                s.push(if line_at(y, x) { junction } else { style.horizontal });
+            }
+            s.extend(std::iter::repeat_n(style.horizontal, width + 2));
+        }
+        if border.get_right() {
+            s.push(right);
+        }
+        s
+    };
+    if border.get_top() {
+        writeln!(writer, "{}", bold(line(style.top_left, style.top_right, style.top_junction, 0)))?;
+    }
+
+    // Render every cell once, in the row it is in.
+    let gap = if separator { 3 } else { 2 };
+    let mut rendered = HashMap::new();
+    for (y, spans) in rows.iter().enumerate() {
+        for span in spans.iter().filter(|s| s.y == y) {
+            let (x, columns) = (span.x, span.columns);
+            let width = widths[x..(x + columns)].iter().sum::<usize>() + gap * (columns - 1);
+            let lines = if is_splitter(span.cell) {
+                // Vertical lines above or below a merged splitter still meet it.
+                let mut s = String::new();
+                for (k, width) in widths.iter().enumerate().skip(x).take(columns) {
+                    if separator && k > x {
+                        s.push(junction(&style, y > 0 && line_at(y - 1, k), line_at(y + 1, k), true, true));
+                    }
+                    s.extend(std::iter::repeat_n(style.horizontal, width + 2));
+                }
+                vec![s]
+            } else {
+                render_cell(span.cell, width)
+            };
+            rendered.insert((x, y), (width, lines));
+        }
+    }
+    // A row is as tall as its tallest cell. A cell with a `row_span` makes the last row it covers taller if needed.
+    let mut heights = vec![1; rows.len()];
+    for (y, spans) in rows.iter().enumerate() {
+        for span in spans.iter().filter(|s| s.y == y) {
+            let height = rendered[&(span.x, y)].1.len();
+            let covered = &mut heights[y..(y + span.rows)];
+            let total = covered.iter().sum::<usize>();
+            if span.rows == 1 {
+                covered[0] = covered[0].max(height);
+            } else if height > total {
+                covered[span.rows - 1] += height - total;
+            }
+        }
+    }
+
+    for (y, spans) in rows.iter().enumerate() {
+        // Only the first line of a row could meet a splitter.
+        for line in 0..heights[y] {
+            if border.get_left() {
+                let left = match spans.first() {
+                    Some(span) if line == 0 && splitter_at(y, span) => style.left_junction,
+                    _ => style.vertical,
+                };
+                write!(writer, "{}", bold(left.to_string()))?;
+            }
+            for (i, span) in spans.iter().enumerate() {
+                if separator && i > 0 {
+                    let (left, right) = (splitter_at(y, &spans[i - 1]), splitter_at(y, span));
+                    let c = if line == 0 && (left || right) {
+                        junction(&style, y > 0 && line_at(y - 1, span.x), line_at(y + 1, span.x), left, right)
+                    } else {
+                        style.vertical
+                    };
+                    write!(writer, "{}", bold(c.to_string()))?;
+                }
+                let (width, lines) = &rendered[&(span.x, span.y)];
+                let index = heights[span.y..y].iter().sum::<usize>() + line;
+                let total = heights[span.y..(span.y + span.rows)].iter().sum::<usize>();
+                let offset = span.cell.get_valign().offset(lines.len(), total);
+                let rendered = match index.checked_sub(offset).and_then(|l| lines.get(l)) {
+                    Some(s) => s.clone(),
+                    None => " ".repeat(width + 2),
+                };
+                match paint {
+                    Paint::None => write!(writer, "{}", rendered)?,
+                    Paint::Terminal => {
+                        let rendered = span
+                            .cell
+                            .get_formatter()
+                            .iter()
+                            .fold(ColoredString::from(rendered.as_str()), |acc, f| f.as_func().run(acc));
+                        write!(writer, "{}", rendered)?;
+                    }
+                    Paint::Always => {
+                        let style = Style::from_formatters(span.cell.get_formatter());
+                        write!(writer, "{}", ansi::paint(&rendered, &style))?;
+                    }
+                }
+            }
+            if border.get_right() {
+                let right = match spans.last() {
+                    Some(span) if line == 0 && splitter_at(y, span) => style.right_junction,
+                    _ => style.vertical,
+                };
+                write!(writer, "{}", bold(right.to_string()))?;
+            }
+            writeln!(writer)?;
+        }
+    }
+    if border.get_bottom() {
+        let last = rows.len().saturating_sub(1);
+        writeln!(writer, "{}", bold(line(style.bottom_left, style.bottom_right, style.bottom_junction, last)))?;
+    }
+    Ok(())
+}
+
+/// The width of the terminal, from the `COLUMNS` environment variable or the terminal of stdout.
+fn terminal_width() -> Option<usize> {
+    match std::env::var("COLUMNS").ok().and_then(|c| c.trim().parse::<usize>().ok()) {
+        Some(columns) if columns > 0 => Some(columns),
+        _ => terminal_size::terminal_size().map(|(terminal_size::Width(w), _)| w as usize),
+    }
+}
+
+/// The character where lines meet, by the directions the lines go to.
+fn junction(style: &BorderStyle, up: bool, down: bool, left: bool, right: bool) -> char {
+    match (up, down, left, right) {
+        (true, true, true, true) => style.cross,
+        (false, true, true, true) => style.top_junction,
+        (true, false, true, true) => style.bottom_junction,
+        (true, true, false, true) => style.left_junction,
+        (true, true, true, false) => style.right_junction,
+        (false, true, false, true) => style.top_left,
+        (false, true, true, false) => style.top_right,
+        (true, false, false, true) => style.bottom_left,
+        (true, false, true, false) => style.bottom_right,
+        (_, _, true, _) | (_, _, _, true) => style.horizontal,
+        _ => style.vertical,
+    }
+}
+
+/// The width of each column, which is the largest `width` of the cells in it.
+pub fn column_widths(table: &Table, columns: usize) -> Vec<usize> {
+    let mut v = vec![0; columns];
+    for row in table.get_table() {
+        for (c, cell) in v.iter_mut().zip(row.iter()) {
+            if let Some(width) = cell.get_width() {
+                *c = (*c).max(width);
+            }
+        }
+    }
+    v
+}
+
+/// Render a cell into lines of `width + 2` characters, including the spaces around it.
+///
+/// Text with line breaks always takes multiple lines, and wrapping may add more.
+///
+/// Splitters are drawn by the caller, since they depend on the lines around them.
+fn render_cell(cell: &Cell, width: usize) -> Vec<String> {
+    match cell.get_content().kind() {
+        ContentKind::Text(ref text) => {
+            let padding = cell.get_padding();
+            let width = width.saturating_sub(padding.get_left() + padding.get_right());
+            let overflow = cell.get_overflow();
+            // Every line of the text is cut or wrapped and aligned on its own.
+            let lines = lines(text)
+                .flat_map(|line| {
+                    if overflow == Overflow::Wrap {
+                        wrap(line, width)
+                    } else if overflow == Overflow::WrapChar {
+                        wrap_char(line, width)
+                    } else {
+                        vec![fit(line, width, overflow)]
+                    }
+                })
+                .collect();
+            balance(lines)
+                .into_iter()
+                .map(|(content, w)| {
+                    let (left, right) = match cell.get_align().kind() {
+                        AlignKind::Left => (0, width - w),
+                        AlignKind::Right => (width - w, 0),
+                        AlignKind::Center => ((width - w) / 2, width - w - (width - w) / 2),
+                    };
+                    format!(
+                        " {}{}{} ",
+                        " ".repeat(padding.get_left() + left),
+                        content,
+                        " ".repeat(right + padding.get_right())
+                    )
+                })
+                .collect()
+        }
+        ContentKind::Splitter | ContentKind::None => vec![" ".repeat(width + 2)],
+    }
+}
+
+/// Cut the text into `width` columns according to the overflow setting, and return it with its width.
+///
+/// Graphemes are never cut, so the result could be narrower than `width` when a wide character does not fit.
+fn fit(text: &str, width: usize, overflow: Overflow) -> (String, usize) {
+    let len = render::width(text);
+    if len <= width {
+        return (text.to_string(), len);
+    }
+    match overflow {
+        _ if overflow.is_ellipsis() && width > 0 => {
+            let dots = if width < 3 { "." } else { "..." };
+            let (kept, len) = take(text, width - dots.len());
+            (kept + dots, len + dots.len())
+        }
+        _ => take(text, width),
+    }
+}
+
+/// The longest start of the text which takes at most `width` columns, with its width.
+///
+/// Escape sequences after the cut are kept, so the styles they set are still closed.
+fn take(text: &str, width: usize) -> (String, usize) {
+    let mut kept = String::new();
+    let mut len = 0;
+    let mut cut = false;
+    for (g, w) in render::graphemes(text) {
+        cut |= len + w > width;
+        if !cut {
+            kept.push_str(g);
+            len += w;
+        } else if g.starts_with('\x1b') {
+            kept.push_str(g);
+        }
+    }
+    (kept, len)
+}
+
+/// Wrap the text at whitespace into lines of at most `width` columns. Words longer than a line are broken.
+///
+/// Whitespace is kept as it is, like indentation or aligned columns, except where a line breaks.
+fn wrap(text: &str, width: usize) -> Vec<(String, usize)> {
+    if width == 0 {
+        return vec![(String::new(), 0)];
+    }
+    let total = render::width(text);
+    if total <= width {
+        return vec![(text.to_string(), total)];
+    }
+    let mut lines = vec![];
+    let mut line = String::new();
+    let mut len = 0;
+    // Whitespace before the next word, which is dropped if the line breaks there.
+    let mut gap = "";
+    for (word, space) in runs(text) {
+        if space {
+            gap = word;
+            continue;
+        }
+        let w = render::width(gap) + render::width(word);
+        if len + w <= width {
+            line.push_str(gap);
+            line.push_str(word);
+            len += w;
+        } else {
+            if len > 0 {
+                lines.push((std::mem::take(&mut line), len));
+                gap = "";
+            }
+            // The line could still hold escape sequences, which go before the word.
+            let mut chunks = chunks(&(std::mem::take(&mut line) + gap + word), width);
+            (line, len) = chunks.pop().unwrap_or_default();
+            lines.extend(chunks);
+        }
+        gap = "";
+    }
+    if len + render::width(gap) <= width {
+        line.push_str(gap);
+        len += render::width(gap);
+    }
+    match lines.last_mut() {
+        Some(last) if len == 0 => last.0.push_str(&line),
+        _ => lines.push((line, len)),
+    }
+    lines
+}
+
+/// Split the text into runs of whitespace and runs of other characters, telling which ones are whitespace.
+fn runs(text: &str) -> Vec<(&str, bool)> {
+    let mut runs = vec![];
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        let space = c.is_whitespace();
+        if i > start && text[start..].starts_with(char::is_whitespace) != space {
+            runs.push((&text[start..i], !space));
+            start = i;
+        }
+    }
+    if start < text.len() {
+        runs.push((&text[start..], text[start..].starts_with(char::is_whitespace)));
+    }
+    runs
+}
+
+/// Close the styles which ANSI escape sequences leave open at the end of every line, and open them again on the next
+/// line, so they do not leak into the borders and the other cells.
+fn balance(lines: Vec<(String, usize)>) -> Vec<(String, usize)> {
+    let mut open = String::new();
+    lines
+        .into_iter()
+        .map(|(line, w)| {
+            let mut s = open.clone() + &line;
+            for (code, _) in render::escapes(&line).filter(|(e, escape)| *escape && e.starts_with("\x1b[") && e.ends_with('m')) {
+                if matches!(code, "\x1b[m" | "\x1b[0m") {
+                    open.clear();
+                } else {
+                    open.push_str(code);
+                }
+            }
+            if !open.is_empty() {
+                s.push_str("\x1b[0m");
+            }
+            (s, w)
+        })
+        .collect()
+}
+
+/// Break the text into lines of at most `width` columns, filling every line but the last one.
+fn wrap_char(text: &str, width: usize) -> Vec<(String, usize)> {
+    let chunks = chunks(text, width);
+    if chunks.is_empty() {
+        return vec![(String::new(), 0)];
+    }
+    chunks
+}
+
+/// Break the text between graphemes into pieces of at most `width` columns.
+/// A grapheme wider than `width` could never fit, so it is dropped.
+fn chunks(text: &str, width: usize) -> Vec<(String, usize)> {
+    let mut chunks = vec![];
+    let mut chunk = String::new();
+    let mut len = 0;
+    for (g, w) in render::graphemes(text) {
+        if w > width {
+            continue;
+        }
+        if len + w > width {
+            chunks.push((std::mem::take(&mut chunk), len));
+            len = 0;
+        }
+        chunk.push_str(g);
+        len += w;
+    }
+    if !chunk.is_empty() {
+        chunks.push((chunk, len));
+    }
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{settings::Shrink, table::Content};
+
+    fn text(s: &str) -> Cell {
+        Cell::default().native_with_content(Content::new(ContentKind::Text(s.to_string())))
+    }
+
+    fn rendered(table: &Table) -> Vec<String> {
+        let mut v = Vec::new();
+        render(table, &mut v, &[], Paint::None).unwrap();
+        String::from_utf8(v).unwrap().lines().map(str::to_string).collect()
+    }
+
+    fn pieces(v: &[(String, usize)]) -> Vec<(&str, usize)> {
+        v.iter().map(|(s, w)| (s.as_str(), *w)).collect()
+    }
+
+    #[test]
+    fn wrap_double_width_at_the_boundary() {
+        assert_eq!(pieces(&wrap("ab 中文", 4)), [("ab", 2), ("中文", 4)]);
+        // A wide character which would stick out by one column goes to the next line.
+        assert_eq!(pieces(&wrap("中文字", 5)), [("中文", 4), ("字", 2)]);
+        assert_eq!(pieces(&wrap("a 中", 3)), [("a", 1), ("中", 2)]);
+        assert_eq!(pieces(&wrap("👍🏽👍🏽 e\u{301}", 4)), [("👍🏽👍🏽", 4), ("e\u{301}", 1)]);
+        assert_eq!(pieces(&wrap_char("a中文", 2)), [("a", 1), ("中", 2), ("文", 2)]);
+        assert_eq!(pieces(&wrap_char("a中文", 3)), [("a中", 3), ("文", 2)]);
+        // A character wider than the line never fits.
+        assert_eq!(pieces(&wrap_char("中", 1)), [("", 0)]);
+    }
+
+    #[test]
+    fn wrap_keeps_whitespace() {
+        assert_eq!(pieces(&wrap("    b  FROM x", 13)), [("    b  FROM x", 13)]);
+        assert_eq!(pieces(&wrap("    b  FROM x  WHERE y", 15)), [("    b  FROM x", 13), ("WHERE y", 7)]);
+        assert_eq!(pieces(&wrap("a\t b  ", 3)), [("a", 1), ("b  ", 3)]);
+        // Indentation which does not fit with the first word is broken with it.
+        assert_eq!(pieces(&wrap("      abc", 4)), [("    ", 4), ("  ab", 4), ("c", 1)]);
+        let table = Table::new(vec![vec![text("SELECT a,\n    b  FROM x")
+            .native_with_overflow(Overflow::Wrap)
+            .native_with_width(Some(12))]]);
+        assert_eq!(rendered(&table), [" SELECT a,    ", "     b  FROM  ", " x            "]);
+    }
+
+    #[test]
+    fn wrap_keeps_styles_across_lines() {
+        assert_eq!(
+            pieces(&balance(wrap("\x1b[31mred text\x1b[0m", 4))),
+            [("\x1b[31mred\x1b[0m", 3), ("\x1b[31mtext\x1b[0m", 4)]
+        );
+        assert_eq!(
+            pieces(&balance(wrap_char("\x1b[1m中文字\x1b[0m!", 4))),
+            [("\x1b[1m中文\x1b[0m", 4), ("\x1b[1m字\x1b[0m!", 3)]
+        );
+        // Escape sequences take no width, so they never start a line on their own.
+        assert_eq!(
+            pieces(&balance(wrap("ab \x1b[4mcd\x1b[24m ef", 2))),
+            [("ab", 2), ("\x1b[4mcd\x1b[24m\x1b[0m", 2), ("\x1b[4m\x1b[24mef\x1b[0m", 2)]
+        );
+    }
+
+    #[test]
+    fn max_width_shrinks_columns() {
+        let table = Table::new(vec![vec![text("aaaaaaaaaa"), text("bbbbbb")]]);
+        assert_eq!(rendered(&table.clone().native_with_max_width(Some(20))), [" aaaaaaaaaa  bbbbbb "]);
+        // 4 columns go to the spaces around the cells, and [10, 6] is shrunk into 8.
+        assert_eq!(rendered(&table.clone().native_with_max_width(Some(12))), [" a...  b... "]);
+        assert_eq!(
+            rendered(&table.clone().native_with_max_width(Some(12)).native_with_shrink(Shrink::Widest)),
+            [" a...  b... "]
+        );
+        // The second column has the lower priority, so it is shrunk down to 1 first.
+        assert_eq!(
+            rendered(&table.native_with_max_width(Some(12)).native_with_shrink(Shrink::priority(vec![1, 0], vec![]))),
+            [" aaaa...  . "]
+        );
+    }
+
+    #[test]
+    fn max_width_keeps_column_min_width() {
+        let table = Table::new(vec![vec![text("aaaaaaaaaa"), text("bbbbbb")]])
+            .native_with_columns(vec![Column::default().native_with_min_width(Some(8))])
+            .native_with_max_width(Some(12));
+        let mut v = Vec::new();
+        table.native_rendered_by(crate::settings::Renderer::Raw, &mut v).unwrap();
+        assert_eq!(String::from_utf8(v).unwrap(), " aaaaa...  . \n");
+    }
+
+    #[test]
+    fn max_width_counts_borders_and_separators() {
+        let table = Table::new(vec![vec![text("aaaaaaaaaa"), text("bbbbbb")]])
+            .native_with_border(crate::settings::Border::All)
+            .native_with_column_separator(true)
+            .native_with_max_width(Some(15));
+        let lines = rendered(&table);
+        assert_eq!(lines[1], "| a... | b... |");
+        assert!(lines.iter().all(|l| render::width(l) <= 15), "{lines:?}");
+    }
+
+    #[test]
+    fn fit_terminal_uses_columns() {
+        let table = Table::new(vec![vec![text("aaaaaaaaaa"), text("bbbbbb")]]).native_with_fit_terminal(true);
+        std::env::set_var("COLUMNS", "12");
+        let fitted = rendered(&table);
+        let narrower = rendered(&table.clone().native_with_max_width(Some(10)));
+        std::env::remove_var("COLUMNS");
+        assert_eq!(fitted, [" a...  b... "]);
+        // The smaller of `max_width` and the terminal wins.
+        assert_eq!(narrower, [" ...  ... "]);
+    }
+}
@@ -1,19 +1,25 @@
 //! Settings for rendering.
 
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
-use table_formatter::table;
 
 use crate::errors::FormatterError;
 
+#[cfg(feature = "python")]
 pub fn regist_classes(py: Python, m: &PyModule) -> PyResult<()> {
     let setting_module = PyModule::new(py, "settings")?;
     Align::regist_self(py, setting_module)?;
+    VAlign::regist_self(py, setting_module)?;
     Overflow::regist_self(py, setting_module)?;
+    Shrink::regist_self(py, setting_module)?;
     Padding::regist_self(py, setting_module)?;
     Color::regist_self(py, setting_module)?;
     Formatter::regist_self(py, setting_module)?;
     Border::regist_self(py, setting_module)?;
+    BorderStyle::regist_self(py, setting_module)?;
     Renderer::regist_self(py, setting_module)?;
+    QuoteStyle::regist_self(py, setting_module)?;
+    MarkdownMerge::regist_self(py, setting_module)?;
     m.add_submodule(setting_module)?;
     Ok(())
 }
@@ -38,72 +44,55 @@ impl<T, U> FormatFunc<T, U> {
 /// 
 /// Use `Formatter.on_color` to create a color for background.
 #[derive(Clone, Copy)]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct Formatter {
     formatter: _Formatter,
 }
 
-#[pymethods]
 impl Formatter {
-    #[classattr]
     #[allow(non_upper_case_globals)]
     pub const Clear: Formatter = Formatter::new(_Formatter::Clear);
-    #[classattr]
     #[allow(non_upper_case_globals)]
     pub const Normal: Formatter = Formatter::new(_Formatter::Normal);
-    #[classattr]
     #[allow(non_upper_case_globals)]
     pub const Bold: Formatter = Formatter::new(_Formatter::Bold);
-    #[classattr]
     #[allow(non_upper_case_globals)]
     pub const Dimmed: Formatter = Formatter::new(_Formatter::Dimmed);
-    #[classattr]
     #[allow(non_upper_case_globals)]
     pub const Italic: Formatter = Formatter::new(_Formatter::Italic);
-    #[classattr]
     #[allow(non_upper_case_globals)]
     pub const Underline: Formatter = Formatter::new(_Formatter::Underline);
-    #[classattr]
     #[allow(non_upper_case_globals)]
     pub const Blink: Formatter = Formatter::new(_Formatter::Blink);
-    #[classattr]
     #[allow(non_upper_case_globals)]
     pub const Reversed: Formatter = Formatter::new(_Formatter::Reversed);
-    #[classattr]
     #[allow(non_upper_case_globals)]
     pub const Hidden: Formatter = Formatter::new(_Formatter::Hidden);
-    #[classattr]
     #[allow(non_upper_case_globals)]
     pub const Strikethrough: Formatter = Formatter::new(_Formatter::Strikethrough);
 
-    #[staticmethod]
     #[inline]
     pub const fn color(color: Color) -> Formatter {
         Formatter::new(_Formatter::Color(_Color::Color(color)))
     }
-    #[staticmethod]
     #[inline]
     pub const fn rbg_color(r: u8, g: u8, b: u8) -> Formatter {
         Formatter::new(_Formatter::Color(_Color::TrueColor { r, g, b }))
     }
 
-    #[staticmethod]
     #[inline]
     pub const fn on_color(color: Color) -> Formatter {
         Formatter::new(_Formatter::OnColor(_Color::Color(color)))
     }
-    #[staticmethod]
     #[inline]
     pub const fn on_rbg_color(r: u8, g: u8, b: u8) -> Formatter {
         Formatter::new(_Formatter::OnColor(_Color::TrueColor { r, g, b }))
     }
 
-    pub fn __repr__(&self) -> String {
+    pub fn repr(&self) -> String {
         format!("<settings.Formatter({})>", self.formatter.repr())
     }
-}
 
-impl Formatter {
     const fn new(fmt: _Formatter) -> Self {
         Self { formatter: fmt }
     }
@@ -112,12 +101,145 @@ impl Formatter {
         self.formatter.as_func()
     }
 
+    #[inline]
+    pub fn apply(&self, style: Style) -> Style {
+        self.formatter.apply(style)
+    }
+
+    /// See `render::json` for the schema.
+    pub fn to_json(self) -> serde_json::Value {
+        use serde_json::json;
+        match self.formatter {
+            _Formatter::Color(c) => json!({ "color": c.to_json() }),
+            _Formatter::OnColor(c) => json!({ "on_color": c.to_json() }),
+            _Formatter::Clear => json!("clear"),
+            _Formatter::Normal => json!("normal"),
+            _Formatter::Bold => json!("bold"),
+            _Formatter::Dimmed => json!("dimmed"),
+            _Formatter::Italic => json!("italic"),
+            _Formatter::Underline => json!("underline"),
+            _Formatter::Blink => json!("blink"),
+            _Formatter::Reversed => json!("reversed"),
+            _Formatter::Hidden => json!("hidden"),
+            _Formatter::Strikethrough => json!("strikethrough"),
+        }
+    }
+
+    /// See `render::json` for the schema.
+    pub fn from_json(value: &serde_json::Value) -> Result<Formatter, FormatterError> {
+        let invalid = || FormatterError::new(format!("Invalid Formatter: {}", value));
+        if let Some(name) = value.as_str() {
+            return Ok(Formatter::new(match name {
+                "clear" => _Formatter::Clear,
+                "normal" => _Formatter::Normal,
+                "bold" => _Formatter::Bold,
+                "dimmed" => _Formatter::Dimmed,
+                "italic" => _Formatter::Italic,
+                "underline" => _Formatter::Underline,
+                "blink" => _Formatter::Blink,
+                "reversed" => _Formatter::Reversed,
+                "hidden" => _Formatter::Hidden,
+                "strikethrough" => _Formatter::Strikethrough,
+                _ => return Err(invalid()),
+            }));
+        }
+        let object = value.as_object().filter(|o| o.len() == 1).ok_or_else(invalid)?;
+        if let Some(c) = object.get("color") {
+            Ok(Formatter::new(_Formatter::Color(_Color::from_json(c)?)))
+        } else if let Some(c) = object.get("on_color") {
+            Ok(Formatter::new(_Formatter::OnColor(_Color::from_json(c)?)))
+        } else {
+            Err(invalid())
+        }
+    }
+
+    #[cfg(feature = "python")]
     fn regist_self(_py: Python, m: &PyModule) -> PyResult<()> {
         m.add_class::<Formatter>()?;
         Ok(())
     }
 }
 
+#[cfg(feature = "python")]
+#[pymethods]
+impl Formatter {
+    #[classattr]
+    #[pyo3(name = "Clear")]
+    fn py_clear() -> Formatter {
+        Formatter::Clear
+    }
+    #[classattr]
+    #[pyo3(name = "Normal")]
+    fn py_normal() -> Formatter {
+        Formatter::Normal
+    }
+    #[classattr]
+    #[pyo3(name = "Bold")]
+    fn py_bold() -> Formatter {
+        Formatter::Bold
+    }
+    #[classattr]
+    #[pyo3(name = "Dimmed")]
+    fn py_dimmed() -> Formatter {
+        Formatter::Dimmed
+    }
+    #[classattr]
+    #[pyo3(name = "Italic")]
+    fn py_italic() -> Formatter {
+        Formatter::Italic
+    }
+    #[classattr]
+    #[pyo3(name = "Underline")]
+    fn py_underline() -> Formatter {
+        Formatter::Underline
+    }
+    #[classattr]
+    #[pyo3(name = "Blink")]
+    fn py_blink() -> Formatter {
+        Formatter::Blink
+    }
+    #[classattr]
+    #[pyo3(name = "Reversed")]
+    fn py_reversed() -> Formatter {
+        Formatter::Reversed
+    }
+    #[classattr]
+    #[pyo3(name = "Hidden")]
+    fn py_hidden() -> Formatter {
+        Formatter::Hidden
+    }
+    #[classattr]
+    #[pyo3(name = "Strikethrough")]
+    fn py_strikethrough() -> Formatter {
+        Formatter::Strikethrough
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "color")]
+    fn py_color(color: Color) -> Formatter {
+        Formatter::color(color)
+    }
+    #[staticmethod]
+    #[pyo3(name = "rbg_color")]
+    fn py_rbg_color(r: u8, g: u8, b: u8) -> Formatter {
+        Formatter::rbg_color(r, g, b)
+    }
+    #[staticmethod]
+    #[pyo3(name = "on_color")]
+    fn py_on_color(color: Color) -> Formatter {
+        Formatter::on_color(color)
+    }
+    #[staticmethod]
+    #[pyo3(name = "on_rbg_color")]
+    fn py_on_rbg_color(r: u8, g: u8, b: u8) -> Formatter {
+        Formatter::on_rbg_color(r, g, b)
+    }
+
+    fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
 #[derive(Clone, Copy)]
 enum _Formatter {
     Color(_Color),
@@ -152,6 +274,23 @@ impl _Formatter {
         }
     }
 
+    fn apply(&self, mut style: Style) -> Style {
+        match self {
+            Self::Clear | Self::Normal => return Style::default(),
+            Self::Bold => style.bold = true,
+            Self::Dimmed => style.dimmed = true,
+            Self::Italic => style.italic = true,
+            Self::Underline => style.underline = true,
+            Self::Blink => style.blink = true,
+            Self::Reversed => style.reversed = true,
+            Self::Hidden => style.hidden = true,
+            Self::Strikethrough => style.strikethrough = true,
+            Self::Color(c) => style.foreground = Some(c.to_rgb()),
+            Self::OnColor(c) => style.background = Some(c.to_rgb()),
+        }
+        style
+    }
+
     fn as_func(&self) -> FormatFunc<colored::ColoredString, colored::ColoredString> {
         use colored::Colorize;
         match self {
@@ -236,11 +375,70 @@ impl _Color {
             Self::Color(c) => c.repr(),
         }
     }
+
+    const fn to_rgb(self) -> (u8, u8, u8) {
+        match self {
+            Self::TrueColor { r, g, b } => (r, g, b),
+            Self::Color(c) => c.to_rgb(),
+        }
+    }
+
+    fn to_json(self) -> serde_json::Value {
+        match self {
+            Self::TrueColor { r, g, b } => serde_json::json!([r, g, b]),
+            Self::Color(c) => serde_json::json!(c.name()),
+        }
+    }
+
+    fn from_json(value: &serde_json::Value) -> Result<Self, FormatterError> {
+        if let Some(name) = value.as_str() {
+            return Ok(Self::Color(Color::native_from_color_name(name)?));
+        }
+        let rgb = value
+            .as_array()
+            .filter(|a| a.len() == 3)
+            .and_then(|a| {
+                a.iter()
+                    .map(|v| v.as_u64().and_then(|v| u8::try_from(v).ok()))
+                    .collect::<Option<Vec<u8>>>()
+            })
+            .ok_or_else(|| FormatterError::new(format!("Invalid Color: {}", value)))?;
+        Ok(Self::TrueColor {
+            r: rgb[0],
+            g: rgb[1],
+            b: rgb[2],
+        })
+    }
+}
+
+/// The text style produced by a list of formatters.
+///
+/// This is what the `colored` formatters would do to a string, for renderers which cannot use ansi codes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Style {
+    pub foreground: Option<(u8, u8, u8)>,
+    pub background: Option<(u8, u8, u8)>,
+    pub bold: bool,
+    pub dimmed: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub blink: bool,
+    pub reversed: bool,
+    pub hidden: bool,
+    pub strikethrough: bool,
+}
+
+impl Style {
+    pub fn from_formatters(formatter: &[Formatter]) -> Style {
+        formatter
+            .iter()
+            .fold(Style::default(), |style, f| f.apply(style))
+    }
 }
 
 /// Pre-defined colors.
 #[derive(Clone, Copy)]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub enum Color {
     Black,
     Red,
@@ -262,10 +460,21 @@ pub enum Color {
     BrightWhite,
 }
 
+#[cfg(feature = "python")]
 #[pymethods]
 impl Color {
     #[staticmethod]
-    pub fn from_color_name(s: String) -> PyResult<Color> {
+    fn from_color_name(s: String) -> PyResult<Color> {
+        Ok(Self::native_from_color_name(&s)?)
+    }
+
+    fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl Color {
+    pub fn native_from_color_name(s: &str) -> Result<Color, FormatterError> {
         let local_str = s.replace(' ', "").to_ascii_lowercase();
         match local_str.as_str() {
             "black" => Ok(Self::Black),
@@ -286,87 +495,119 @@ impl Color {
             "brightpurple" => Ok(Self::BrightPurple),
             "brightcyan" => Ok(Self::BrightCyan),
             "brightwhite" => Ok(Self::BrightWhite),
-            _ => Err(FormatterError::new(format!("Invalid Color Name: {}", s)).into()),
+            _ => Err(FormatterError::new(format!("Invalid Color Name: {}", s))),
         }
     }
 
-    pub fn __repr__(&self) -> String {
-        self.repr()
-    }
-}
-
-impl Color {
+    #[cfg(feature = "python")]
     fn regist_self(_py: Python, m: &PyModule) -> PyResult<()> {
         m.add_class::<Color>()?;
         Ok(())
     }
 
+    /// The color in the xterm palette.
+    pub const fn to_rgb(self) -> (u8, u8, u8) {
+        match self {
+            Self::Black => (0, 0, 0),
+            Self::Red => (205, 0, 0),
+            Self::Green => (0, 205, 0),
+            Self::Yellow => (205, 205, 0),
+            Self::Blue => (0, 0, 238),
+            Self::Magenta | Self::Purple => (205, 0, 205),
+            Self::Cyan => (0, 205, 205),
+            Self::White => (229, 229, 229),
+            Self::BrightBlack => (127, 127, 127),
+            Self::BrightRed => (255, 0, 0),
+            Self::BrightGreen => (0, 255, 0),
+            Self::BrightYellow => (255, 255, 0),
+            Self::BrightBlue => (92, 92, 255),
+            Self::BrightMagenta | Self::BrightPurple => (255, 0, 255),
+            Self::BrightCyan => (0, 255, 255),
+            Self::BrightWhite => (255, 255, 255),
+        }
+    }
+
     pub fn repr(&self) -> String {
-        format!(
-            "<settings.Color({})>",
-            match self {
-                Self::Black => "black",
-                Self::Red => "red",
-                Self::Green => "green",
-                Self::Yellow => "yellow",
-                Self::Blue => "blue",
-                Self::Magenta => "magenta",
-                Self::Purple => "purple",
-                Self::Cyan => "cyan",
-                Self::White => "white",
-                Self::BrightBlack => "bright black",
-                Self::BrightRed => "bright red",
-                Self::BrightGreen => "bright green",
-                Self::BrightYellow => "bright yellow",
-                Self::BrightBlue => "bright blue",
-                Self::BrightMagenta => "bright magenta",
-                Self::BrightPurple => "bright purple",
-                Self::BrightCyan => "bright cyan",
-                Self::BrightWhite => "bright white",
-            }
-        )
+        format!("<settings.Color({})>", self.name())
+    }
+
+    /// The name of the color, which could be parsed by `Color.from_color_name`.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Black => "black",
+            Self::Red => "red",
+            Self::Green => "green",
+            Self::Yellow => "yellow",
+            Self::Blue => "blue",
+            Self::Magenta => "magenta",
+            Self::Purple => "purple",
+            Self::Cyan => "cyan",
+            Self::White => "white",
+            Self::BrightBlack => "bright black",
+            Self::BrightRed => "bright red",
+            Self::BrightGreen => "bright green",
+            Self::BrightYellow => "bright yellow",
+            Self::BrightBlue => "bright blue",
+            Self::BrightMagenta => "bright magenta",
+            Self::BrightPurple => "bright purple",
+            Self::BrightCyan => "bright cyan",
+            Self::BrightWhite => "bright white",
+        }
     }
 }
 
 /// Padding around the content.
 /// 
 /// A string `"hello"` with `Padding{ left: 1, right: 1 }` will become `" ell "` but not `" hello "`.
-#[derive(Clone, Copy, Default)]
-#[pyclass]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "python", pyclass(get_all, set_all))]
 pub struct Padding {
-    #[pyo3(get, set)]
     left: usize,
-    #[pyo3(get, set)]
     right: usize,
 }
 
+#[cfg(feature = "python")]
 #[pymethods]
 impl Padding {
     #[classattr]
+    #[pyo3(name = "Empty")]
+    fn py_empty() -> Padding {
+        Padding::Empty
+    }
+    #[new]
+    fn __new__(left: usize, right: usize) -> Padding {
+        Padding::new(left, right)
+    }
+    fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl Padding {
     #[allow(non_upper_case_globals)]
     pub const Empty: Padding = Padding::new(0, 0);
-    #[new]
     #[inline]
     pub const fn new(left: usize, right: usize) -> Padding {
         Padding { left, right }
     }
-    pub fn __repr__(&self) -> String {
+    pub fn repr(&self) -> String {
         format!(
             "<settings.Padding(left: {}, right: {})>",
             self.left, self.right
         )
     }
-}
 
-impl Padding {
     #[inline]
-    pub const fn to_native(self) -> table::Padding {
-        table::Padding {
-            left: self.left,
-            right: self.right,
-        }
+    pub const fn get_left(&self) -> usize {
+        self.left
     }
 
+    #[inline]
+    pub const fn get_right(&self) -> usize {
+        self.right
+    }
+
+    #[cfg(feature = "python")]
     fn regist_self(_py: Python, m: &PyModule) -> PyResult<()> {
         m.add_class::<Padding>()?;
         Ok(())
@@ -374,139 +615,469 @@ impl Padding {
 }
 
 /// Text alignment.
-#[derive(Clone, Copy, Default)]
-#[pyclass]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct Align {
-    align: table::Align,
+    align: AlignKind,
+}
+
+/// The alignments of `Align`, which renderers match on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AlignKind {
+    #[default]
+    Left,
+    Center,
+    Right,
 }
 
+#[cfg(feature = "python")]
 #[pymethods]
 impl Align {
     #[classattr]
-    #[allow(non_upper_case_globals)]
-    pub const Left: Align = Align::new(table::Align::Left);
+    #[pyo3(name = "Left")]
+    fn py_left() -> Align {
+        Align::Left
+    }
     #[classattr]
-    #[allow(non_upper_case_globals)]
-    pub const Right: Align = Align::new(table::Align::Right);
+    #[pyo3(name = "Right")]
+    fn py_right() -> Align {
+        Align::Right
+    }
     #[classattr]
+    #[pyo3(name = "Center")]
+    fn py_center() -> Align {
+        Align::Center
+    }
+    fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl Align {
+    #[allow(non_upper_case_globals)]
+    pub const Left: Align = Align::new(AlignKind::Left);
     #[allow(non_upper_case_globals)]
-    pub const Center: Align = Align::new(table::Align::Center);
-    pub fn __repr__(&self) -> String {
+    pub const Right: Align = Align::new(AlignKind::Right);
+    #[allow(non_upper_case_globals)]
+    pub const Center: Align = Align::new(AlignKind::Center);
+    pub fn repr(&self) -> String {
         format!(
             "<settings.Align({})>",
             match self.align {
-                table::Align::Left => "Left",
-                table::Align::Right => "Right",
-                table::Align::Center => "Center",
+                AlignKind::Left => "Left",
+                AlignKind::Right => "Right",
+                AlignKind::Center => "Center",
             }
         )
     }
-}
 
-impl Align {
     #[inline]
-    pub const fn new(align: table::Align) -> Align {
+    pub const fn new(align: AlignKind) -> Align {
         Align { align }
     }
 
     #[inline]
-    pub const fn to_native(self) -> table::Align {
+    pub const fn kind(self) -> AlignKind {
         self.align
     }
 
+    #[cfg(feature = "python")]
     fn regist_self(_py: Python, m: &PyModule) -> PyResult<()> {
         m.add_class::<Align>()?;
         Ok(())
     }
 }
 
+/// Vertical alignment of a cell, used when its row is taller than its content.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "python", pyclass)]
+pub enum VAlign {
+    #[default]
+    Top,
+    Middle,
+    Bottom,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl VAlign {
+    fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl VAlign {
+    pub fn repr(&self) -> String {
+        format!("<settings.VAlign({:?})>", self)
+    }
+
+    /// How many lines to leave blank above `height` lines of content in a row of `row_height` lines.
+    pub const fn offset(self, height: usize, row_height: usize) -> usize {
+        let space = row_height.saturating_sub(height);
+        match self {
+            Self::Top => 0,
+            Self::Middle => space / 2,
+            Self::Bottom => space,
+        }
+    }
+
+    #[cfg(feature = "python")]
+    fn regist_self(_py: Python, m: &PyModule) -> PyResult<()> {
+        m.add_class::<VAlign>()?;
+        Ok(())
+    }
+}
+
 /// Overflow setting for cells.
 /// 
 /// `Overflow.Ellipse`: "hello world" -> "he..."
 ///
 /// `Overflow.Hide`: "hello" -> "hello"
-#[derive(Clone, Copy, Default)]
-#[pyclass]
+///
+/// `Overflow.Wrap`: "hello world" -> "hello" and "world" in two lines, breaking words only when they are too long.
+///
+/// `Overflow.WrapChar`: "hello world" -> "hello" and " worl" and "d" in three lines.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct Overflow {
-    overflow: table::Overflow,
+    overflow: _Overflow,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum _Overflow {
+    Hidden,
+    #[default]
+    Ellipsis,
+    Wrap,
+    WrapChar,
 }
 
+#[cfg(feature = "python")]
 #[pymethods]
 impl Overflow {
     #[classattr]
-    #[allow(non_upper_case_globals)]
-    pub const Hide: Overflow = Overflow::new(table::Overflow::Hidden);
+    #[pyo3(name = "Hide")]
+    fn py_hide() -> Overflow {
+        Overflow::Hide
+    }
     #[classattr]
+    #[pyo3(name = "Ellipse")]
+    fn py_ellipse() -> Overflow {
+        Overflow::Ellipse
+    }
+    #[classattr]
+    #[pyo3(name = "Wrap")]
+    fn py_wrap() -> Overflow {
+        Overflow::Wrap
+    }
+    #[classattr]
+    #[pyo3(name = "WrapChar")]
+    fn py_wrap_char() -> Overflow {
+        Overflow::WrapChar
+    }
+    fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl Overflow {
+    #[allow(non_upper_case_globals)]
+    pub const Hide: Overflow = Overflow { overflow: _Overflow::Hidden };
+    #[allow(non_upper_case_globals)]
+    pub const Ellipse: Overflow = Overflow { overflow: _Overflow::Ellipsis };
+    #[allow(non_upper_case_globals)]
+    pub const Wrap: Overflow = Overflow { overflow: _Overflow::Wrap };
     #[allow(non_upper_case_globals)]
-    pub const Ellipse: Overflow = Overflow::new(table::Overflow::Ellipsis);
-    pub fn __repr__(&self) -> String {
+    pub const WrapChar: Overflow = Overflow { overflow: _Overflow::WrapChar };
+    pub fn repr(&self) -> String {
         format!(
             "<settings.Overflow({})>",
             match self.overflow {
-                table::Overflow::Ellipsis => "Ellipsis",
-                table::Overflow::Hidden => "Hidden",
+                _Overflow::Ellipsis => "Ellipsis",
+                _Overflow::Hidden => "Hidden",
+                _Overflow::Wrap => "Wrap",
+                _Overflow::WrapChar => "WrapChar",
             }
         )
     }
-}
 
-impl Overflow {
+    /// Whether the cell grows vertically instead of cutting the content.
     #[inline]
-    pub const fn new(overflow: table::Overflow) -> Overflow {
-        Overflow { overflow }
+    pub const fn is_wrap(self) -> bool {
+        matches!(self.overflow, _Overflow::Wrap | _Overflow::WrapChar)
     }
 
+    /// Whether the cut content ends with `...`.
     #[inline]
-    pub const fn to_native(self) -> table::Overflow {
-        self.overflow
+    pub const fn is_ellipsis(self) -> bool {
+        matches!(self.overflow, _Overflow::Ellipsis)
     }
 
+    /// The name used by json, see `render::json`.
+    pub const fn name(self) -> &'static str {
+        match self.overflow {
+            _Overflow::Ellipsis => "ellipsis",
+            _Overflow::Hidden => "hidden",
+            _Overflow::Wrap => "wrap",
+            _Overflow::WrapChar => "wrap_char",
+        }
+    }
+
+    #[cfg(feature = "python")]
     fn regist_self(_py: Python, m: &PyModule) -> PyResult<()> {
         m.add_class::<Overflow>()?;
         Ok(())
     }
 }
 
+/// How `Table.max_width` shrinks the columns of a table which is too wide.
+///
+/// `Shrink.Proportional`: every column gives up the same share of its width.
+///
+/// `Shrink.Widest`: the widest column is shrunk first, until it is as narrow as the next widest one, and so on.
+///
+/// `Shrink.priority(priority, min_width)`: columns are shrunk one by one, from the lowest priority to the highest,
+/// each down to its minimum width. Columns missing from the lists have a priority of 0 and a minimum width of 1,
+/// and among columns with the same priority the rightmost one is shrunk first.
+///
+/// Columns are never shrunk below 1 character, unless a smaller minimum width is given.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "python", pyclass)]
+pub struct Shrink {
+    shrink: _Shrink,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+enum _Shrink {
+    #[default]
+    Proportional,
+    Widest,
+    Priority {
+        priority: Vec<usize>,
+        min_width: Vec<usize>,
+    },
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl Shrink {
+    #[classattr]
+    #[pyo3(name = "Proportional")]
+    fn py_proportional() -> Shrink {
+        Shrink::Proportional
+    }
+    #[classattr]
+    #[pyo3(name = "Widest")]
+    fn py_widest() -> Shrink {
+        Shrink::Widest
+    }
+    #[staticmethod]
+    #[pyo3(name = "priority", signature = (priority, min_width = vec![]))]
+    fn py_priority(priority: Vec<usize>, min_width: Vec<usize>) -> Shrink {
+        Shrink::priority(priority, min_width)
+    }
+    fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl Shrink {
+    #[allow(non_upper_case_globals)]
+    pub const Proportional: Shrink = Shrink { shrink: _Shrink::Proportional };
+    #[allow(non_upper_case_globals)]
+    pub const Widest: Shrink = Shrink { shrink: _Shrink::Widest };
+
+    #[inline]
+    pub const fn priority(priority: Vec<usize>, min_width: Vec<usize>) -> Shrink {
+        Shrink {
+            shrink: _Shrink::Priority { priority, min_width },
+        }
+    }
+
+    pub fn repr(&self) -> String {
+        match self.shrink {
+            _Shrink::Proportional => "<settings.Shrink(Proportional)>".to_string(),
+            _Shrink::Widest => "<settings.Shrink(Widest)>".to_string(),
+            _Shrink::Priority { ref priority, ref min_width } => {
+                format!("<settings.Shrink(priority: {:?}, min_width: {:?})>", priority, min_width)
+            }
+        }
+    }
+
+    /// Shrink the widths of columns until they add up to at most `total`, or until no column could be shrunk.
+    /// No column is shrunk below its floor in `floors`, which is its `Column.min_width`.
+    pub fn shrink(&self, widths: &mut [usize], floors: &[usize], total: usize) {
+        let mut excess = widths.iter().sum::<usize>().saturating_sub(total);
+        if excess == 0 {
+            return;
+        }
+        let min_width = match self.shrink {
+            _Shrink::Priority { ref min_width, .. } => min_width.as_slice(),
+            _ => &[],
+        };
+        // How much each column could give up.
+        let room = widths
+            .iter()
+            .enumerate()
+            .map(|(x, w)| {
+                let min = min_width.get(x).copied().unwrap_or(1);
+                w.saturating_sub(min.max(floors.get(x).copied().unwrap_or(0)))
+            })
+            .collect::<Vec<_>>();
+        // How much each column gives up.
+        let mut cuts = vec![0; widths.len()];
+        match self.shrink {
+            _Shrink::Proportional => {
+                let sum = room.iter().sum::<usize>();
+                if sum <= excess {
+                    cuts = room;
+                } else {
+                    // Every column gives up the same share of its room, rounded down,
+                    // and the rest is taken from the columns with the most room left.
+                    for (cut, r) in cuts.iter_mut().zip(room.iter()) {
+                        *cut = r * excess / sum;
+                    }
+                    let mut order = (0..widths.len()).collect::<Vec<_>>();
+                    order.sort_by_key(|x| std::cmp::Reverse(room[*x] - cuts[*x]));
+                    let rest = excess - cuts.iter().sum::<usize>();
+                    for x in order.into_iter().take(rest) {
+                        cuts[x] += 1;
+                    }
+                }
+            }
+            _Shrink::Widest => {
+                while excess > 0 {
+                    // The widest column which could still be shrunk, the leftmost one among equals.
+                    let widest = (0..widths.len())
+                        .filter(|x| cuts[*x] < room[*x])
+                        .max_by_key(|x| (widths[*x] - cuts[*x], std::cmp::Reverse(*x)));
+                    match widest {
+                        Some(x) => {
+                            cuts[x] += 1;
+                            excess -= 1;
+                        }
+                        None => break,
+                    }
+                }
+            }
+            _Shrink::Priority { ref priority, .. } => {
+                let mut order = (0..widths.len()).rev().collect::<Vec<_>>();
+                order.sort_by_key(|x| priority.get(*x).copied().unwrap_or(0));
+                for x in order {
+                    cuts[x] = room[x].min(excess);
+                    excess -= cuts[x];
+                }
+            }
+        }
+        for (w, cut) in widths.iter_mut().zip(cuts) {
+            *w -= cut;
+        }
+    }
+
+    /// See `render::json` for the schema.
+    pub fn to_json(&self) -> serde_json::Value {
+        use serde_json::json;
+        match self.shrink {
+            _Shrink::Proportional => json!("proportional"),
+            _Shrink::Widest => json!("widest"),
+            _Shrink::Priority { ref priority, ref min_width } => {
+                json!({ "priority": priority, "min_width": min_width })
+            }
+        }
+    }
+
+    /// See `render::json` for the schema.
+    pub fn from_json(value: &serde_json::Value) -> Result<Shrink, FormatterError> {
+        let invalid = || FormatterError::new(format!("Invalid Shrink: {}", value));
+        match value.as_str() {
+            Some("proportional") => return Ok(Shrink::Proportional),
+            Some("widest") => return Ok(Shrink::Widest),
+            Some(_) => return Err(invalid()),
+            None => {}
+        }
+        let object = value.as_object().ok_or_else(invalid)?;
+        let list = |key: &str| -> Result<Vec<usize>, FormatterError> {
+            match object.get(key) {
+                None => Ok(vec![]),
+                Some(v) => v
+                    .as_array()
+                    .ok_or_else(invalid)?
+                    .iter()
+                    .map(|n| n.as_u64().map(|n| n as usize).ok_or_else(invalid))
+                    .collect(),
+            }
+        };
+        Ok(Shrink::priority(list("priority")?, list("min_width")?))
+    }
+
+    #[cfg(feature = "python")]
+    fn regist_self(_py: Python, m: &PyModule) -> PyResult<()> {
+        m.add_class::<Shrink>()?;
+        Ok(())
+    }
+}
+
 /// Border of the table.
+///
+/// The characters used to draw the border are set by `style`, see `BorderStyle`.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass(get_all, set_all))]
 pub struct Border {
-    #[pyo3(get, set)]
     left: bool,
-    #[pyo3(get, set)]
     right: bool,
-    #[pyo3(get, set)]
     top: bool,
-    #[pyo3(get, set)]
     bottom: bool,
+    style: BorderStyle,
 }
 
+#[cfg(feature = "python")]
 #[pymethods]
 impl Border {
     #[classattr]
-    #[allow(non_upper_case_globals)]
-    pub const Default: Border = Border::new(false, false, false, false);
+    #[pyo3(name = "Default")]
+    fn py_default() -> Border {
+        Border::Default
+    }
     #[classattr]
-    #[allow(non_upper_case_globals)]
-    pub const Horizontal: Border = Border::new(false, false, true, true);
+    #[pyo3(name = "Horizontal")]
+    fn py_horizontal() -> Border {
+        Border::Horizontal
+    }
     #[classattr]
-    #[allow(non_upper_case_globals)]
-    pub const Vertical: Border = Border::new(true, true, false, false);
+    #[pyo3(name = "Vertical")]
+    fn py_vertical() -> Border {
+        Border::Vertical
+    }
     #[classattr]
-    #[allow(non_upper_case_globals)]
-    pub const All: Border = Border::new(true, true, true, true);
+    #[pyo3(name = "All")]
+    fn py_all() -> Border {
+        Border::All
+    }
     #[new]
-    #[inline]
-    pub const fn __new__(left: bool, right: bool, top: bool, bottom: bool) -> Self {
-        Self::new(left, right, top, bottom)
+    #[pyo3(signature = (left, right, top, bottom, style = BorderStyle::Default))]
+    fn __new__(left: bool, right: bool, top: bool, bottom: bool, style: BorderStyle) -> Self {
+        Self::new(left, right, top, bottom).native_with_style(style)
     }
 
-    pub fn __repr__(&self) -> String {
-        format!("<settings.Border(l:{}, r:{}, t:{}, b:{})>", self.left, self.right, self.top, self.bottom)
+    fn with_style(&self, style: BorderStyle) -> Self {
+        self.native_with_style(style)
+    }
+
+    fn __repr__(&self) -> String {
+        self.repr()
     }
 }
 
 impl Border {
+    #[allow(non_upper_case_globals)]
+    pub const Default: Border = Border::new(false, false, false, false);
+    #[allow(non_upper_case_globals)]
+    pub const Horizontal: Border = Border::new(false, false, true, true);
+    #[allow(non_upper_case_globals)]
+    pub const Vertical: Border = Border::new(true, true, false, false);
+    #[allow(non_upper_case_globals)]
+    pub const All: Border = Border::new(true, true, true, true);
+
     #[inline]
     pub const fn new(left: bool, right: bool, top: bool, bottom: bool) -> Self {
         Self {
@@ -514,55 +1085,521 @@ impl Border {
             right,
             top,
             bottom,
+            style: BorderStyle::Default,
         }
     }
 
+    /// Copy the border with another `BorderStyle`.
+    #[inline]
+    pub const fn native_with_style(mut self, style: BorderStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    #[inline]
+    pub const fn get_style(&self) -> BorderStyle {
+        self.style
+    }
+
+    #[inline]
+    pub const fn get_left(&self) -> bool {
+        self.left
+    }
+
+    #[inline]
+    pub const fn get_right(&self) -> bool {
+        self.right
+    }
+
+    #[inline]
+    pub const fn get_top(&self) -> bool {
+        self.top
+    }
+
     #[inline]
-    pub const fn to_native(self) -> table::Border {
-        table::Border::new(self.left, self.right, self.top, self.bottom)
+    pub const fn get_bottom(&self) -> bool {
+        self.bottom
     }
 
+    pub fn repr(&self) -> String {
+        format!(
+            "<settings.Border(l:{}, r:{}, t:{}, b:{}, style:{})>",
+            self.left,
+            self.right,
+            self.top,
+            self.bottom,
+            self.style.repr()
+        )
+    }
+
+    #[cfg(feature = "python")]
     fn regist_self(_py: Python, m: &PyModule) -> PyResult<()> {
         m.add_class::<Border>()?;
         Ok(())
     }
 }
 
+/// Characters used to draw the border and the splitters of a table.
+///
+/// Use one of the presets, or create a custom style with every character.
+/// `BorderStyle.Default` is the style of the previous versions, which draws horizontal lines with `─` and vertical lines with `|`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "python", pyclass(get_all, set_all))]
+pub struct BorderStyle {
+    /// Top and bottom border, and splitters.
+    pub horizontal: char,
+    /// Left and right border.
+    pub vertical: char,
+    pub top_left: char,
+    pub top_right: char,
+    pub bottom_left: char,
+    pub bottom_right: char,
+    /// Where a vertical line meets a horizontal line from below, e.g. on the top border.
+    pub top_junction: char,
+    /// Where a vertical line meets a horizontal line from above, e.g. on the bottom border.
+    pub bottom_junction: char,
+    /// Where a horizontal line starts from a vertical line, e.g. a splitter on the left border.
+    pub left_junction: char,
+    /// Where a horizontal line ends at a vertical line, e.g. a splitter on the right border.
+    pub right_junction: char,
+    /// Where a splitter crosses a column separator.
+    pub cross: char,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl BorderStyle {
+    #[classattr]
+    #[pyo3(name = "Default")]
+    fn py_default() -> BorderStyle {
+        BorderStyle::Default
+    }
+    #[classattr]
+    #[pyo3(name = "Ascii")]
+    fn py_ascii() -> BorderStyle {
+        BorderStyle::Ascii
+    }
+    #[classattr]
+    #[pyo3(name = "Single")]
+    fn py_single() -> BorderStyle {
+        BorderStyle::Single
+    }
+    #[classattr]
+    #[pyo3(name = "Double")]
+    fn py_double() -> BorderStyle {
+        BorderStyle::Double
+    }
+    #[classattr]
+    #[pyo3(name = "Rounded")]
+    fn py_rounded() -> BorderStyle {
+        BorderStyle::Rounded
+    }
+    #[classattr]
+    #[pyo3(name = "Heavy")]
+    fn py_heavy() -> BorderStyle {
+        BorderStyle::Heavy
+    }
+
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    fn __new__(
+        horizontal: char,
+        vertical: char,
+        top_left: char,
+        top_right: char,
+        bottom_left: char,
+        bottom_right: char,
+        top_junction: char,
+        bottom_junction: char,
+        left_junction: char,
+        right_junction: char,
+        cross: char,
+    ) -> Self {
+        Self {
+            horizontal,
+            vertical,
+            top_left,
+            top_right,
+            bottom_left,
+            bottom_right,
+            top_junction,
+            bottom_junction,
+            left_junction,
+            right_junction,
+            cross,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl Default for BorderStyle {
+    fn default() -> Self {
+        Self::Default
+    }
+}
+
+impl BorderStyle {
+    #[allow(non_upper_case_globals)]
+    pub const Default: BorderStyle = BorderStyle::from_chars(['─', '|', '─', '─', '─', '─', '─', '─', '|', '|', '|']);
+    /// `+`, `-` and `|`, for terminals without unicode fonts.
+    #[allow(non_upper_case_globals)]
+    pub const Ascii: BorderStyle = BorderStyle::from_chars(['-', '|', '+', '+', '+', '+', '+', '+', '+', '+', '+']);
+    #[allow(non_upper_case_globals)]
+    pub const Single: BorderStyle = BorderStyle::from_chars(['─', '│', '┌', '┐', '└', '┘', '┬', '┴', '├', '┤', '┼']);
+    #[allow(non_upper_case_globals)]
+    pub const Double: BorderStyle = BorderStyle::from_chars(['═', '║', '╔', '╗', '╚', '╝', '╦', '╩', '╠', '╣', '╬']);
+    #[allow(non_upper_case_globals)]
+    pub const Rounded: BorderStyle = BorderStyle::from_chars(['─', '│', '╭', '╮', '╰', '╯', '┬', '┴', '├', '┤', '┼']);
+    #[allow(non_upper_case_globals)]
+    pub const Heavy: BorderStyle = BorderStyle::from_chars(['━', '┃', '┏', '┓', '┗', '┛', '┳', '┻', '┣', '┫', '╋']);
+
+    /// The presets with their names, which are used by `repr` and json.
+    pub const PRESETS: [(&'static str, BorderStyle); 6] = [
+        ("default", BorderStyle::Default),
+        ("ascii", BorderStyle::Ascii),
+        ("single", BorderStyle::Single),
+        ("double", BorderStyle::Double),
+        ("rounded", BorderStyle::Rounded),
+        ("heavy", BorderStyle::Heavy),
+    ];
+
+    /// Create a style from characters in the order of the fields.
+    pub const fn from_chars(chars: [char; 11]) -> BorderStyle {
+        BorderStyle {
+            horizontal: chars[0],
+            vertical: chars[1],
+            top_left: chars[2],
+            top_right: chars[3],
+            bottom_left: chars[4],
+            bottom_right: chars[5],
+            top_junction: chars[6],
+            bottom_junction: chars[7],
+            left_junction: chars[8],
+            right_junction: chars[9],
+            cross: chars[10],
+        }
+    }
+
+    /// The characters in the order of the fields.
+    pub const fn to_chars(self) -> [char; 11] {
+        [
+            self.horizontal,
+            self.vertical,
+            self.top_left,
+            self.top_right,
+            self.bottom_left,
+            self.bottom_right,
+            self.top_junction,
+            self.bottom_junction,
+            self.left_junction,
+            self.right_junction,
+            self.cross,
+        ]
+    }
+
+    /// The name of the preset, if the style is one of them.
+    pub fn name(&self) -> Option<&'static str> {
+        Self::PRESETS
+            .iter()
+            .find(|(_, style)| style == self)
+            .map(|(name, _)| *name)
+    }
+
+    /// Parse the name of a preset case-insensitively.
+    pub fn native_from_name(s: &str) -> Result<BorderStyle, FormatterError> {
+        let name = s.to_ascii_lowercase();
+        Self::PRESETS
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, style)| *style)
+            .ok_or_else(|| FormatterError::new(format!("Invalid BorderStyle Name: {}", s)))
+    }
+
+    pub fn repr(&self) -> String {
+        match self.name() {
+            Some(name) => format!("<settings.BorderStyle({})>", name),
+            None => format!("<settings.BorderStyle({:?})>", self.to_chars().iter().collect::<String>()),
+        }
+    }
+
+    /// See `render::json` for the schema.
+    pub fn to_json(self) -> serde_json::Value {
+        match self.name() {
+            Some(name) => serde_json::Value::from(name),
+            None => serde_json::Value::from(self.to_chars().iter().collect::<String>()),
+        }
+    }
+
+    pub fn from_json(value: &serde_json::Value) -> Result<BorderStyle, FormatterError> {
+        let invalid = || FormatterError::new(format!("Invalid BorderStyle: {}", value));
+        let s = value.as_str().ok_or_else(invalid)?;
+        let chars = s.chars().collect::<Vec<_>>();
+        match <[char; 11]>::try_from(chars) {
+            Ok(chars) => Ok(Self::from_chars(chars)),
+            Err(_) => Self::native_from_name(s).map_err(|_| invalid()),
+        }
+    }
+
+    #[cfg(feature = "python")]
+    fn regist_self(_py: Python, m: &PyModule) -> PyResult<()> {
+        m.add_class::<BorderStyle>()?;
+        Ok(())
+    }
+}
+
 /// Render settings.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub enum Renderer {
-    /// Render a normal table, with ansi color settings.
+    /// Render a normal table, with ansi color settings. Text with line breaks takes multiple lines in its row.
     Normal,
-    /// Render a raw table, containing only contents.
+    /// Render a raw table, containing only contents. Line breaks are rendered the same way as `Normal`.
     Raw,
-    /// Render a markdown-formatted table. The alignment is determined by **the first row**, and the alignment of the rest of the table will be *ignored*.
+    /// Render a markdown-formatted table. The first row is the header, and the alignment of each column is its
+    /// `Column.align`, or else the one of its first cell below the header.
+    ///
+    /// `|` in text is escaped, and line breaks become `<br>`. Merged cells and splitter rows are rendered according to
+    /// `Table.markdown_merge`.
     Markdown,
+    /// Render a html `<table>`. Rows above the first splitter row become the `<thead>`, and formatters are translated into inline styles.
+    Html,
+    /// Render a LaTeX `tabular` environment. Splitter rows and borders become `booktabs` rules, so `\usepackage{booktabs}` is required.
+    Latex,
+    /// Render a reStructuredText grid table, which Sphinx accepts. Rows above the first splitter row are the header, and
+    /// both `merge` and `row_span` are kept. Formatters are not rendered.
+    RstGrid,
+    /// Render a reStructuredText simple table, whose header is the rows above the first splitter row. Merged cells are
+    /// underlined with `---`, and the rows covered by a `row_span` are left blank. Formatters are not rendered.
+    RstSimple,
+    /// Render an AsciiDoc `|===` block, with a `cols` attribute from the alignment of the columns. `merge` and
+    /// `row_span` become span specifiers like `2+|`, and the first row is the header if the table has a splitter row.
+    AsciiDoc,
+    /// Render an Emacs Org table. Splitter rows become `|---+---|` rules, and the cells covered by a `merge` or a
+    /// `row_span` are left blank.
+    Org,
+    /// Render a MediaWiki `{| class="wikitable"` table. Rows above the first splitter row are header cells, and `merge`
+    /// and `row_span` become `colspan` and `rowspan`.
+    MediaWiki,
+    /// Render Jira or Confluence wiki markup. Rows above the first splitter row are header rows written with `||`,
+    /// and the cells covered by a `merge` or a `row_span` are left blank.
+    JiraWiki,
 }
 
+#[cfg(feature = "python")]
 #[pymethods]
 impl Renderer {
-    pub fn __repr__(&self) -> String {
+    fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl Renderer {
+    pub fn repr(&self) -> String {
         format!("<settings.Renderer({})>", match self {
             Self::Normal => "Normal",
             Self::Raw => "Raw",
             Self::Markdown => "Markdown",
+            Self::Html => "Html",
+            Self::Latex => "Latex",
+            Self::RstGrid => "RstGrid",
+            Self::RstSimple => "RstSimple",
+            Self::AsciiDoc => "AsciiDoc",
+            Self::Org => "Org",
+            Self::MediaWiki => "MediaWiki",
+            Self::JiraWiki => "JiraWiki",
         })
     }
+
+    /// Parse the name of a renderer case-insensitively, e.g. `"markdown"`. `-` and `_` are ignored, so `"rst-grid"`
+    /// is `RstGrid`.
+    pub fn native_from_name(s: &str) -> Result<Renderer, FormatterError> {
+        match s.to_ascii_lowercase().replace(['-', '_'], "").as_str() {
+            "normal" => Ok(Self::Normal),
+            "raw" => Ok(Self::Raw),
+            "markdown" => Ok(Self::Markdown),
+            "html" => Ok(Self::Html),
+            "latex" => Ok(Self::Latex),
+            "rstgrid" => Ok(Self::RstGrid),
+            "rstsimple" => Ok(Self::RstSimple),
+            "asciidoc" => Ok(Self::AsciiDoc),
+            "org" => Ok(Self::Org),
+            "mediawiki" => Ok(Self::MediaWiki),
+            "jirawiki" => Ok(Self::JiraWiki),
+            _ => Err(FormatterError::new(format!("Invalid Renderer Name: {}", s))),
+        }
+    }
+
+    #[cfg(feature = "python")]
+    fn regist_self(_py: Python, m: &PyModule) -> PyResult<()> {
+        m.add_class::<Renderer>()?;
+        Ok(())
+    }
 }
 
-impl Renderer {
-    #[inline]
-    pub fn to_native(self) -> table::Renderer {
+/// How `Renderer.Markdown` renders what markdown tables could not express, which are cells with a `merge` or a
+/// `row_span`, and splitter rows other than the one below the header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "python", pyclass)]
+pub enum MarkdownMerge {
+    /// Leave the cells covered by a merged cell blank, and render splitter rows as rows of `───`.
+    #[default]
+    Blank,
+    /// Repeat the content of a merged cell in every cell it covers, and render splitter rows as rows of `───`.
+    Repeat,
+    /// Render the whole table as an html `<table>`, like `Renderer.Html`, which GitHub and GitLab accept in markdown.
+    /// Tables which markdown could express are still rendered as markdown.
+    Html,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl MarkdownMerge {
+    fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl MarkdownMerge {
+    pub fn repr(&self) -> String {
+        format!("<settings.MarkdownMerge({:?})>", self)
+    }
+
+    /// The name used by json, see `render::json`.
+    pub const fn name(self) -> &'static str {
         match self {
-            Self::Normal => table::Renderer::Normal,
-            Self::Raw => table::Renderer::Raw,
-            Self::Markdown => table::Renderer::Markdown,
+            Self::Blank => "blank",
+            Self::Repeat => "repeat",
+            Self::Html => "html",
         }
     }
 
+    /// Parse the name of a policy case-insensitively, e.g. `"repeat"`.
+    pub fn native_from_name(s: &str) -> Result<MarkdownMerge, FormatterError> {
+        match s.to_ascii_lowercase().as_str() {
+            "blank" => Ok(Self::Blank),
+            "repeat" => Ok(Self::Repeat),
+            "html" => Ok(Self::Html),
+            _ => Err(FormatterError::new(format!("Invalid MarkdownMerge Name: {}", s))),
+        }
+    }
+
+    #[cfg(feature = "python")]
     fn regist_self(_py: Python, m: &PyModule) -> PyResult<()> {
-        m.add_class::<Renderer>()?;
+        m.add_class::<MarkdownMerge>()?;
+        Ok(())
+    }
+}
+
+/// Quoting rule for csv export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "python", pyclass)]
+pub enum QuoteStyle {
+    /// Quote fields containing the delimiter, quotes or line breaks.
+    #[default]
+    Necessary,
+    /// Quote every field.
+    Always,
+    /// Quote every field that is not a number.
+    NonNumeric,
+    /// Never quote fields. The output may be invalid csv.
+    Never,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl QuoteStyle {
+    fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl QuoteStyle {
+    pub fn repr(&self) -> String {
+        format!("<settings.QuoteStyle({})>", match self {
+            Self::Necessary => "Necessary",
+            Self::Always => "Always",
+            Self::NonNumeric => "NonNumeric",
+            Self::Never => "Never",
+        })
+    }
+
+    #[cfg(feature = "python")]
+    fn regist_self(_py: Python, m: &PyModule) -> PyResult<()> {
+        m.add_class::<QuoteStyle>()?;
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shrunk(shrink: &Shrink, widths: &[usize], total: usize) -> Vec<usize> {
+        let mut widths = widths.to_vec();
+        shrink.shrink(&mut widths, &[], total);
+        widths
+    }
+
+    #[test]
+    fn shrink_fits_or_keeps() {
+        for shrink in [Shrink::Proportional, Shrink::Widest, Shrink::priority(vec![], vec![])] {
+            assert_eq!(shrunk(&shrink, &[10, 6, 4], 20), [10, 6, 4]);
+            assert_eq!(shrunk(&shrink, &[10, 6, 4], 30), [10, 6, 4]);
+        }
+    }
+
+    #[test]
+    fn shrink_proportional() {
+        // The columns give up 9 * [9, 5, 3] / 17 = [4, 2, 1], and the rest goes to the ones with the most room left.
+        assert_eq!(shrunk(&Shrink::Proportional, &[10, 6, 4], 11), [5, 3, 3]);
+        assert_eq!(shrunk(&Shrink::Proportional, &[10, 6, 4], 17), [8, 5, 4]);
+        // Below the sum of the minimums, every column keeps one character.
+        assert_eq!(shrunk(&Shrink::Proportional, &[10, 6, 4], 2), [1, 1, 1]);
+        assert_eq!(shrunk(&Shrink::Proportional, &[10, 0, 4], 0), [1, 0, 1]);
+    }
+
+    #[test]
+    fn shrink_widest() {
+        assert_eq!(shrunk(&Shrink::Widest, &[10, 6, 4], 11), [3, 4, 4]);
+        assert_eq!(shrunk(&Shrink::Widest, &[10, 6, 4], 16), [6, 6, 4]);
+        assert_eq!(shrunk(&Shrink::Widest, &[6, 6, 4], 15), [5, 6, 4]);
+        assert_eq!(shrunk(&Shrink::Widest, &[10, 6, 4], 0), [1, 1, 1]);
+    }
+
+    #[test]
+    fn shrink_priority() {
+        // Columns with a lower priority are shrunk first, down to their `min_width`.
+        let shrink = Shrink::priority(vec![2, 0, 1], vec![3, 1, 2]);
+        assert_eq!(shrunk(&shrink, &[10, 6, 4], 16), [10, 2, 4]);
+        assert_eq!(shrunk(&shrink, &[10, 6, 4], 11), [8, 1, 2]);
+        assert_eq!(shrunk(&shrink, &[10, 6, 4], 0), [3, 1, 2]);
+        // A column narrower than its `min_width` is left alone.
+        assert_eq!(shrunk(&shrink, &[2, 6, 4], 0), [2, 1, 2]);
+        // Among equal priorities the rightmost column goes first, and a missing `min_width` is 1.
+        let shrink = Shrink::priority(vec![], vec![4]);
+        assert_eq!(shrunk(&shrink, &[10, 6, 4], 15), [10, 4, 1]);
+        assert_eq!(shrunk(&shrink, &[10, 6, 4], 0), [4, 1, 1]);
+    }
+
+    #[test]
+    fn shrink_keeps_floors() {
+        let shrunk = |shrink: &Shrink, floors: &[usize], total| {
+            let mut widths = vec![10, 6, 4];
+            shrink.shrink(&mut widths, floors, total);
+            widths
+        };
+        for shrink in [Shrink::Proportional, Shrink::Widest, Shrink::priority(vec![], vec![])] {
+            assert_eq!(shrunk(&shrink, &[0, 5], 0), [1, 5, 1]);
+            // A floor above the width leaves the column as it is.
+            assert_eq!(shrunk(&shrink, &[0, 8], 0), [1, 6, 1]);
+        }
+        assert_eq!(shrunk(&Shrink::Proportional, &[0, 5], 11), [3, 6, 2]);
+        assert_eq!(shrunk(&Shrink::Widest, &[0, 5], 11), [3, 5, 3]);
+        // The larger of the floor and the `min_width` of the strategy wins.
+        let shrink = Shrink::priority(vec![1, 0, 0], vec![2, 3, 0]);
+        assert_eq!(shrunk(&shrink, &[4, 0, 1], 0), [4, 3, 1]);
+    }
+}
@@ -1,20 +1,35 @@
 //! Core library and the main entry point.
 
-use std::rc::Rc;
+use std::{borrow::Cow, io};
+#[cfg(feature = "python")]
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
 
 use itertools::Itertools;
-use pyo3::{exceptions, prelude::*, types::PyList};
-use table_formatter::table;
+#[cfg(feature = "python")]
+use pyo3::{
+    exceptions,
+    prelude::*,
+    types::{PyBytes, PyList},
+};
 
 use crate::{
-    errors::FormatterError,
-    settings::{Align, Border, Formatter, Overflow, Padding, Renderer},
+    errors::{FormatterError, TableError},
+    load,
+    render::{self, text::Paint},
+    settings::{
+        Align, Border, Formatter, MarkdownMerge, Overflow, Padding, QuoteStyle, Renderer, Shrink, VAlign,
+    },
 };
 
+#[cfg(feature = "python")]
 pub fn regist_table(py: Python, m: &PyModule) -> PyResult<()> {
     let table_module = PyModule::new(py, "table")?;
     Content::regist_self(py, table_module)?;
     Cell::regist_self(py, table_module)?;
+    Column::regist_self(py, table_module)?;
     Table::regist_self(py, table_module)?;
     m.add_submodule(table_module)?;
     Ok(())
@@ -26,48 +41,85 @@ pub fn regist_table(py: Python, m: &PyModule) -> PyResult<()> {
 /// - Splitter: horizontal splitter. Looks like "─".
 /// - Empty: empty content.
 #[derive(Clone, Default)]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct Content {
-    content: table::Content,
+    content: ContentKind,
+}
+
+/// The kinds of `Content`, which renderers match on.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ContentKind {
+    Text(String),
+    Splitter,
+    #[default]
+    None,
 }
 
+#[cfg(feature = "python")]
 #[pymethods]
 impl Content {
     #[classattr]
-    #[allow(non_upper_case_globals)]
-    pub const Empty: Content = Content::new(table::Content::None);
+    #[pyo3(name = "Empty")]
+    fn py_empty() -> Content {
+        Content::Empty
+    }
     #[classattr]
-    #[allow(non_upper_case_globals)]
-    pub const Splitter: Content = Content::new(table::Content::Splitter);
+    #[pyo3(name = "Splitter")]
+    fn py_splitter() -> Content {
+        Content::Splitter
+    }
 
     #[new]
-    pub fn __new__(c: String) -> Content {
-        Content::new(table::Content::Text(c))
+    fn __new__(c: String) -> Content {
+        Content::text(c)
     }
 
-    pub fn __repr__(&self) -> String {
+    fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl Content {
+    #[allow(non_upper_case_globals)]
+    pub const Empty: Content = Content::new(ContentKind::None);
+    #[allow(non_upper_case_globals)]
+    pub const Splitter: Content = Content::new(ContentKind::Splitter);
+
+    #[inline]
+    pub const fn text(c: String) -> Content {
+        Content::new(ContentKind::Text(c))
+    }
+
+    pub fn repr(&self) -> String {
         format!(
             "<table.Content({})>",
             match self.content {
-                table::Content::Splitter => "<Splitter>".to_string(),
-                table::Content::None => "<None>".to_string(),
-                table::Content::Text(ref text) => format!("\"{}\"", text),
+                ContentKind::Splitter => "<Splitter>".to_string(),
+                ContentKind::None => "<None>".to_string(),
+                ContentKind::Text(ref text) => format!("\"{}\"", text),
             }
         )
     }
-}
 
-impl Content {
     #[inline]
-    pub const fn new(content: table::Content) -> Content {
+    pub const fn new(content: ContentKind) -> Content {
         Content { content }
     }
 
+    /// The width of the widest line of the text, or `None` for splitters and empty content.
+    pub fn get_width(&self) -> Option<usize> {
+        match self.content {
+            ContentKind::Text(ref text) => render::lines(text).map(render::width).max(),
+            ContentKind::Splitter | ContentKind::None => None,
+        }
+    }
+
     #[inline]
-    pub fn into_native(self) -> table::Content {
-        self.content
+    pub const fn kind(&self) -> &ContentKind {
+        &self.content
     }
 
+    #[cfg(feature = "python")]
     fn regist_self(_py: Python, m: &PyModule) -> PyResult<()> {
         m.add_class::<Content>()?;
         Ok(())
@@ -76,51 +128,42 @@ impl Content {
 
 /// Basic item for rendering a table.
 #[derive(Clone, Default)]
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass(get_all, set_all))]
 pub struct Cell {
-    #[pyo3(get, set)]
     content: Content,
-    #[pyo3(get, set)]
-    overflow: Overflow,
-    #[pyo3(get, set)]
+    /// `None` leaves the overflow to the column, or to the default.
+    overflow: Option<Overflow>,
     width: Option<usize>,
-    #[pyo3(get, set)]
-    align: Align,
-    #[pyo3(get, set)]
-    padding: Padding,
-    #[pyo3(get, set)]
+    /// `None` leaves the alignment to the column, or to the default.
+    align: Option<Align>,
+    valign: VAlign,
+    /// `None` leaves the padding to the column, or to the default.
+    padding: Option<Padding>,
     merge: Option<usize>,
-    #[pyo3(get, set)]
+    /// Number of following rows the cell covers. The covered cells must be `Content.Empty`.
+    row_span: Option<usize>,
     formatter: Vec<Formatter>,
 }
 
+#[cfg(feature = "python")]
 #[pymethods]
 impl Cell {
     #[new]
-    pub fn __new__(c: Content) -> Self {
+    fn __new__(c: Content) -> Self {
         Cell::default().native_with_content(c)
     }
 
-    pub fn __repr__(&self) -> String {
-        format!(
-            "<table.Cell(content={}, overflow={}, width={:?}, align={}, padding={}, merge={:?}, formatter=[{}])>",
-            self.content.__repr__(),
-            self.overflow.__repr__(),
-            self.width,
-            self.align.__repr__(),
-            self.padding.__repr__(),
-            self.merge,
-            self.formatter.iter().map(|f| f.__repr__()).join(",")
-        )
+    fn __repr__(&self) -> String {
+        self.repr()
     }
 
     pub fn with_content(mut this: PyRefMut<Self>, content: Content) -> PyRefMut<Self> {
-        this.width = content.content.get_width();
+        this.width = content.get_width();
         this.content = content;
         this
     }
     pub fn with_overflow(mut this: PyRefMut<Self>, overflow: Overflow) -> PyRefMut<Self> {
-        this.overflow = overflow;
+        this.overflow = Some(overflow);
         this
     }
     pub fn with_width(mut this: PyRefMut<Self>, width: Option<usize>) -> PyRefMut<Self> {
@@ -128,17 +171,25 @@ impl Cell {
         this
     }
     pub fn with_align(mut this: PyRefMut<Self>, align: Align) -> PyRefMut<Self> {
-        this.align = align;
+        this.align = Some(align);
+        this
+    }
+    pub fn with_valign(mut this: PyRefMut<Self>, valign: VAlign) -> PyRefMut<Self> {
+        this.valign = valign;
         this
     }
     pub fn with_padding(mut this: PyRefMut<Self>, padding: Padding) -> PyRefMut<Self> {
-        this.padding = padding;
+        this.padding = Some(padding);
         this
     }
     pub fn with_merge(mut this: PyRefMut<Self>, merge: Option<usize>) -> PyRefMut<Self> {
         this.merge = merge;
         this
     }
+    pub fn with_row_span(mut this: PyRefMut<Self>, row_span: Option<usize>) -> PyRefMut<Self> {
+        this.row_span = row_span;
+        this
+    }
     pub fn with_formatter(mut this: PyRefMut<Self>, formatter: Vec<Formatter>) -> PyRefMut<Self> {
         this.formatter = formatter;
         this
@@ -165,21 +216,33 @@ impl Cell {
     /// 
     /// Using `with_span(x)` will generate a vector with the cell *itself* and **x** empty cells.
     pub fn with_span(&self, span: usize) -> Vec<Self> {
-        let this = self.clone().native_with_merge(Some(span)).native_with_width(None);
-        let mut v = vec![this];
-        v.extend(std::iter::repeat(Self::default()).take(span));
-        v
+        self.native_with_span(span)
     }
 }
 
 impl Cell {
+    pub fn repr(&self) -> String {
+        format!(
+            "<table.Cell(content={}, overflow={}, width={:?}, align={}, valign={}, padding={}, merge={:?}, row_span={:?}, formatter=[{}])>",
+            self.content.repr(),
+            self.overflow.map_or("None".to_string(), |o| o.repr()),
+            self.width,
+            self.align.map_or("None".to_string(), |a| a.repr()),
+            self.valign.repr(),
+            self.padding.map_or("None".to_string(), |p| p.repr()),
+            self.merge,
+            self.row_span,
+            self.formatter.iter().map(|f| f.repr()).join(",")
+        )
+    }
+
     pub fn native_with_content(mut self, content: Content) -> Self {
-        self.width = content.content.get_width();
+        self.width = content.get_width();
         self.content = content;
         self
     }
     pub fn native_with_overflow(mut self, overflow: Overflow) -> Self {
-        self.overflow = overflow;
+        self.overflow = Some(overflow);
         self
     }
     pub fn native_with_width(mut self, width: Option<usize>) -> Self {
@@ -187,17 +250,25 @@ impl Cell {
         self
     }
     pub fn native_with_align(mut self, align: Align) -> Self {
-        self.align = align;
+        self.align = Some(align);
+        self
+    }
+    pub fn native_with_valign(mut self, valign: VAlign) -> Self {
+        self.valign = valign;
         self
     }
     pub fn native_with_padding(mut self, padding: Padding) -> Self {
-        self.padding = padding;
+        self.padding = Some(padding);
         self
     }
     pub fn native_with_merge(mut self, merge: Option<usize>) -> Self {
         self.merge = merge;
         self
     }
+    pub fn native_with_row_span(mut self, row_span: Option<usize>) -> Self {
+        self.row_span = row_span;
+        self
+    }
     pub fn native_with_formatter(mut self, formatter: Vec<Formatter>) -> Self {
         self.formatter = formatter;
         self
@@ -210,59 +281,294 @@ impl Cell {
         self
     }
     pub fn native_set_overflow(&mut self, overflow: Overflow) {
-        self.overflow = overflow;
+        self.overflow = Some(overflow);
     }
 
-    pub fn to_native(&self) -> table::Cell {
-        table::Cell::default()
-            .with_content(self.content.clone().into_native())
-            .with_align(self.align.to_native())
-            .with_overflow(self.overflow.to_native())
-            .with_width(self.width)
-            .with_padding(self.padding.to_native())
-            .with_merge(self.merge)
-            .with_formatter(
-                self.formatter
-                    .iter()
-                    .cloned()
-                    .map(|f| {
-                        table::FormatterFunc::Boxed(Rc::new(Box::new(move |s| f.as_func().run(s))))
-                    })
-                    .collect_vec(),
-            )
+    /// Automatically generate a cross-cell item, see `Cell.with_span`.
+    pub fn native_with_span(&self, span: usize) -> Vec<Self> {
+        let this = self.clone().native_with_merge(Some(span)).native_with_width(None);
+        let mut v = vec![this];
+        v.extend(std::iter::repeat_n(Self::default(), span));
+        v
     }
 
+    pub fn get_content(&self) -> &Content {
+        &self.content
+    }
+    pub fn get_overflow(&self) -> Overflow {
+        self.overflow.unwrap_or_default()
+    }
+    pub fn get_width(&self) -> Option<usize> {
+        self.width
+    }
+    pub fn get_align(&self) -> Align {
+        self.align.unwrap_or_default()
+    }
+    pub fn get_valign(&self) -> VAlign {
+        self.valign
+    }
+    pub fn get_padding(&self) -> Padding {
+        self.padding.unwrap_or_default()
+    }
+    /// The overflow set on the cell, or `None` if it is left to the column.
+    pub fn get_explicit_overflow(&self) -> Option<Overflow> {
+        self.overflow
+    }
+    /// The alignment set on the cell, or `None` if it is left to the column.
+    pub fn get_explicit_align(&self) -> Option<Align> {
+        self.align
+    }
+    /// The padding set on the cell, or `None` if it is left to the column.
+    pub fn get_explicit_padding(&self) -> Option<Padding> {
+        self.padding
+    }
+    pub fn get_merge(&self) -> Option<usize> {
+        self.merge
+    }
+    pub fn get_row_span(&self) -> Option<usize> {
+        self.row_span
+    }
+    pub fn get_formatter(&self) -> &[Formatter] {
+        &self.formatter
+    }
+
+    #[cfg(feature = "python")]
     fn regist_self(_py: Python, m: &PyModule) -> PyResult<()> {
         m.add_class::<Cell>()?;
         Ok(())
     }
 }
 
+/// Settings shared by the cells in a column of a table, see `Table.columns`.
+///
+/// Each property applies to the cells of the column which leave it unset, so a cell overrides it by
+/// setting a value of its own, even the default one. The formatters of the column are applied before the ones of the cell.
+///
+/// `width` fixes the width of the column, while `min_width` and `max_width` bound it. `min_width` also holds when the
+/// table is shrunk by `Table.shrink`.
+///
+/// When any column has a `header`, a header row with a splitter below it is put above the rows of the table.
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "python", pyclass(get_all, set_all))]
+pub struct Column {
+    align: Option<Align>,
+    width: Option<usize>,
+    min_width: Option<usize>,
+    max_width: Option<usize>,
+    overflow: Option<Overflow>,
+    padding: Option<Padding>,
+    formatter: Vec<Formatter>,
+    header: Option<String>,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl Column {
+    #[new]
+    #[pyo3(signature = (
+        align = None,
+        width = None,
+        min_width = None,
+        max_width = None,
+        overflow = None,
+        padding = None,
+        formatter = vec![],
+        header = None,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn __new__(
+        align: Option<Align>,
+        width: Option<usize>,
+        min_width: Option<usize>,
+        max_width: Option<usize>,
+        overflow: Option<Overflow>,
+        padding: Option<Padding>,
+        formatter: Vec<Formatter>,
+        header: Option<String>,
+    ) -> Self {
+        Column {
+            align,
+            width,
+            min_width,
+            max_width,
+            overflow,
+            padding,
+            formatter,
+            header,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        self.repr()
+    }
+
+    pub fn with_align(mut this: PyRefMut<Self>, align: Option<Align>) -> PyRefMut<Self> {
+        this.align = align;
+        this
+    }
+    pub fn with_width(mut this: PyRefMut<Self>, width: Option<usize>) -> PyRefMut<Self> {
+        this.width = width;
+        this
+    }
+    pub fn with_min_width(mut this: PyRefMut<Self>, min_width: Option<usize>) -> PyRefMut<Self> {
+        this.min_width = min_width;
+        this
+    }
+    pub fn with_max_width(mut this: PyRefMut<Self>, max_width: Option<usize>) -> PyRefMut<Self> {
+        this.max_width = max_width;
+        this
+    }
+    pub fn with_overflow(mut this: PyRefMut<Self>, overflow: Option<Overflow>) -> PyRefMut<Self> {
+        this.overflow = overflow;
+        this
+    }
+    pub fn with_padding(mut this: PyRefMut<Self>, padding: Option<Padding>) -> PyRefMut<Self> {
+        this.padding = padding;
+        this
+    }
+    pub fn with_formatter(mut this: PyRefMut<Self>, formatter: Vec<Formatter>) -> PyRefMut<Self> {
+        this.formatter = formatter;
+        this
+    }
+    pub fn with_header(mut this: PyRefMut<Self>, header: Option<String>) -> PyRefMut<Self> {
+        this.header = header;
+        this
+    }
+}
+
+impl Column {
+    pub fn repr(&self) -> String {
+        format!(
+            "<table.Column(align={}, width={:?}, min_width={:?}, max_width={:?}, overflow={}, padding={}, formatter=[{}], header={:?})>",
+            self.align.map_or("None".to_string(), |a| a.repr()),
+            self.width,
+            self.min_width,
+            self.max_width,
+            self.overflow.map_or("None".to_string(), |o| o.repr()),
+            self.padding.map_or("None".to_string(), |p| p.repr()),
+            self.formatter.iter().map(|f| f.repr()).join(","),
+            self.header,
+        )
+    }
+
+    pub fn native_with_align(mut self, align: Option<Align>) -> Self {
+        self.align = align;
+        self
+    }
+    pub fn native_with_width(mut self, width: Option<usize>) -> Self {
+        self.width = width;
+        self
+    }
+    pub fn native_with_min_width(mut self, min_width: Option<usize>) -> Self {
+        self.min_width = min_width;
+        self
+    }
+    pub fn native_with_max_width(mut self, max_width: Option<usize>) -> Self {
+        self.max_width = max_width;
+        self
+    }
+    pub fn native_with_overflow(mut self, overflow: Option<Overflow>) -> Self {
+        self.overflow = overflow;
+        self
+    }
+    pub fn native_with_padding(mut self, padding: Option<Padding>) -> Self {
+        self.padding = padding;
+        self
+    }
+    pub fn native_with_formatter(mut self, formatter: Vec<Formatter>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+    pub fn native_with_header(mut self, header: Option<String>) -> Self {
+        self.header = header;
+        self
+    }
+
+    pub fn get_align(&self) -> Option<Align> {
+        self.align
+    }
+    pub fn get_width(&self) -> Option<usize> {
+        self.width
+    }
+    pub fn get_min_width(&self) -> Option<usize> {
+        self.min_width
+    }
+    pub fn get_max_width(&self) -> Option<usize> {
+        self.max_width
+    }
+    pub fn get_overflow(&self) -> Option<Overflow> {
+        self.overflow
+    }
+    pub fn get_padding(&self) -> Option<Padding> {
+        self.padding
+    }
+    pub fn get_formatter(&self) -> &[Formatter] {
+        &self.formatter
+    }
+    pub fn get_header(&self) -> Option<&str> {
+        self.header.as_deref()
+    }
+
+    /// Apply the settings of the column to a cell in it.
+    pub fn apply(&self, mut cell: Cell) -> Cell {
+        cell.align = cell.align.or(self.align);
+        cell.overflow = cell.overflow.or(self.overflow);
+        cell.padding = cell.padding.or(self.padding);
+        if !self.formatter.is_empty() {
+            cell.formatter = self.formatter.iter().copied().chain(cell.formatter).collect();
+        }
+        // Cells without content get a width too, so the bounds hold in a column of splitters.
+        let width = self.width.or(cell.width);
+        let width = match self.min_width {
+            Some(min) => Some(width.unwrap_or(0).max(min)),
+            None => width,
+        };
+        cell.width = width.map(|w| self.max_width.map_or(w, |max| w.min(max)));
+        cell
+    }
+
+    #[cfg(feature = "python")]
+    fn regist_self(_py: Python, m: &PyModule) -> PyResult<()> {
+        m.add_class::<Column>()?;
+        Ok(())
+    }
+}
+
 /// This is the main entry point of the lib, which represents the table to render.
 ///
 /// For more information, please see the lib's documentation.
-#[pyclass]
+#[derive(Clone)]
+#[cfg_attr(feature = "python", pyclass(get_all))]
 pub struct Table {
-    #[pyo3(get)]
     table: Vec<Vec<Cell>>,
-    #[pyo3(get, set)]
+    /// Settings of each column, see `Column`. Columns missing from the list use only the settings of their cells.
+    columns: Vec<Column>,
     border: Border,
+    /// Draw vertical lines between columns, with the characters of the border's style.
+    column_separator: bool,
+    /// Maximum width of the whole table, including its borders, when rendered as text.
+    /// Columns are shrunk by `shrink` to fit, and the overflow setting of each cell is applied.
+    max_width: Option<usize>,
+    shrink: Shrink,
+    /// Fit the table into the width of the terminal when rendered as text, like `max_width`.
+    ///
+    /// The width is taken from the `COLUMNS` environment variable, or from the terminal of stdout.
+    /// When neither is available, e.g. when stdout is piped into a file, the width is not limited.
+    fit_terminal: bool,
+    /// How `Renderer.Markdown` renders merged cells and splitter rows, see `MarkdownMerge`.
+    markdown_merge: MarkdownMerge,
 }
 
+#[cfg(feature = "python")]
 #[pymethods]
 impl Table {
-    pub fn __repr__(&self) -> String {
-        format!(
-            "<table.Table(border={}, size=({}))>",
-            self.border.__repr__(),
-            if self.table.is_empty() {
-                "0x0".to_string()
-            } else {
-                let h = self.table.len();
-                let w = self.table[0].len();
-                format!("{}x{}", w, h)
-            }
-        )
+    fn __repr__(&self) -> String {
+        self.repr()
+    }
+
+    #[setter]
+    fn set_border(&mut self, border: Border) {
+        self.border = border;
     }
 
     pub fn with_border(mut this: PyRefMut<Self>, border: Border) -> PyRefMut<Self> {
@@ -270,24 +576,108 @@ impl Table {
         this
     }
 
+    #[setter]
+    fn set_columns(&mut self, columns: Vec<Column>) {
+        self.columns = columns;
+    }
+
+    pub fn with_columns(mut this: PyRefMut<Self>, columns: Vec<Column>) -> PyRefMut<Self> {
+        this.columns = columns;
+        this
+    }
+
+    #[setter]
+    fn set_column_separator(&mut self, column_separator: bool) {
+        self.column_separator = column_separator;
+    }
+
+    pub fn with_column_separator(mut this: PyRefMut<Self>, column_separator: bool) -> PyRefMut<Self> {
+        this.column_separator = column_separator;
+        this
+    }
+
+    #[setter]
+    fn set_max_width(&mut self, max_width: Option<usize>) {
+        self.max_width = max_width;
+    }
+
+    pub fn with_max_width(mut this: PyRefMut<Self>, max_width: Option<usize>) -> PyRefMut<Self> {
+        this.max_width = max_width;
+        this
+    }
+
+    #[setter]
+    fn set_shrink(&mut self, shrink: Shrink) {
+        self.shrink = shrink;
+    }
+
+    pub fn with_shrink(mut this: PyRefMut<Self>, shrink: Shrink) -> PyRefMut<Self> {
+        this.shrink = shrink;
+        this
+    }
+
+    #[setter]
+    fn set_fit_terminal(&mut self, fit_terminal: bool) {
+        self.fit_terminal = fit_terminal;
+    }
+
+    pub fn with_fit_terminal(mut this: PyRefMut<Self>, fit_terminal: bool) -> PyRefMut<Self> {
+        this.fit_terminal = fit_terminal;
+        this
+    }
+
+    #[setter]
+    fn set_markdown_merge(&mut self, markdown_merge: MarkdownMerge) {
+        self.markdown_merge = markdown_merge;
+    }
+
+    pub fn with_markdown_merge(mut this: PyRefMut<Self>, markdown_merge: MarkdownMerge) -> PyRefMut<Self> {
+        this.markdown_merge = markdown_merge;
+        this
+    }
+
     /// This will render the table according to the render settings. See the lib's documentation for more information.
     pub fn render(&self, setting: Renderer) -> PyResult<String> {
-        let setting = setting.to_native();
-        let table = self.to_native();
-        let mut v = Vec::new();
-        table
-            .rendered_by(setting, &mut v)
-            .map_err(|e| FormatterError::new(format!("{e}")))?;
-        String::from_utf8(v).map_err(PyErr::new::<exceptions::PyUnicodeError, _>)
+        render_to_string(|v| self.native_rendered_by(setting, v))
     }
-    
+
+    /// Export the table as csv. Splitter rows are dropped.
+    ///
+    /// When `fill_merge` is set to true, the content of a merged cell is repeated in every column it covers, otherwise these columns are left blank.
+    ///
+    /// Use `delimiter="\t"` to export tsv.
+    #[pyo3(signature = (delimiter = ',', quote_style = QuoteStyle::Necessary, fill_merge = false))]
+    pub fn to_csv(&self, delimiter: char, quote_style: QuoteStyle, fill_merge: bool) -> PyResult<String> {
+        render_to_string(|v| self.native_to_csv(v, delimiter, quote_style, fill_merge))
+    }
+
+    /// Export the table as json, keeping every property of the cells.
+    ///
+    /// The schema is documented in the "JSON" section of the README, and the output could be loaded again by `Table.from_json`.
+    #[pyo3(signature = (pretty = false))]
+    pub fn to_json(&self, pretty: bool) -> PyResult<String> {
+        render_to_string(|v| self.native_to_json(v, pretty))
+    }
+
+    /// Render the table in a html `<pre>` block, which looks the same as `Renderer.Normal` in a terminal,
+    /// with `<span style>` elements in place of the ANSI escape sequences of the formatters.
+    ///
+    /// The styles are kept even when stdout is not a terminal or `NO_COLOR` is set.
+    pub fn render_html_pre(&self) -> PyResult<String> {
+        render_to_string(|v| self.native_render_html_pre(v))
+    }
+
+    /// Render the table as an SVG image of what `Renderer.Normal` prints in a terminal, with the styles of the formatters.
+    ///
+    /// Every character takes a monospace cell of `0.6 * font_size` by `1.2 * font_size` pixels, so `font_family` should be a monospace font.
+    #[pyo3(signature = (font_family = "monospace", font_size = 14.0))]
+    pub fn to_svg(&self, font_family: &str, font_size: f64) -> PyResult<String> {
+        render_to_string(|v| self.native_to_svg(v, font_family, font_size))
+    }
+
     /// This function will overwrite the `overflow` property of every cells in the table.
     pub fn overwrite_overflow(&mut self, overflow: Overflow) {
-        for row in self.table.iter_mut() {
-            for cell in row.iter_mut() {
-                cell.native_set_overflow(overflow)
-            }
-        }
+        self.native_overwrite_overflow(overflow)
     }
 
     /// Create a new table with some rows.
@@ -338,6 +728,66 @@ impl Table {
 
         Ok(Table::native_create(header_v, content_v, splitter))
     }
+
+    /// Create a new table from csv.
+    ///
+    /// `source` could be a path, a file-like object or the csv text itself.
+    /// A string is treated as a path only if it is a single line naming an existing file.
+    ///
+    /// When `has_header` is set to true, the first record is used as the header, see `Table.create`.
+    #[staticmethod]
+    #[pyo3(signature = (source, has_header = true, delimiter = ',', splitter = true))]
+    pub fn from_csv(
+        source: &PyAny,
+        has_header: bool,
+        delimiter: char,
+        splitter: bool,
+    ) -> PyResult<Table> {
+        let text = read_source(source)?;
+        Ok(Table::native_from_csv(&text, has_header, delimiter, splitter)?)
+    }
+
+    /// Create a new table from json exported by `Table.to_json`.
+    ///
+    /// `source` could be a path, a file-like object or the json text itself, see `Table.from_csv`.
+    #[staticmethod]
+    pub fn from_json(source: &PyAny) -> PyResult<Table> {
+        let text = read_source(source)?;
+        Ok(Table::native_from_json(&text)?)
+    }
+}
+
+/// Run a renderer and collect the output.
+#[cfg(feature = "python")]
+fn render_to_string(
+    render: impl FnOnce(&mut Vec<u8>) -> Result<(), TableError>,
+) -> PyResult<String> {
+    let mut v = Vec::new();
+    render(&mut v).map_err(FormatterError::from)?;
+    String::from_utf8(v).map_err(PyErr::new::<exceptions::PyUnicodeError, _>)
+}
+
+/// Read text from a path, a file-like object or the text itself.
+#[cfg(feature = "python")]
+fn read_source(source: &PyAny) -> PyResult<String> {
+    if source.hasattr("read")? {
+        let data = source.call_method0("read")?;
+        if let Ok(bytes) = data.downcast::<PyBytes>() {
+            String::from_utf8(bytes.as_bytes().to_vec())
+                .map_err(PyErr::new::<exceptions::PyUnicodeError, _>)
+        } else {
+            data.extract::<String>()
+        }
+    } else if let Ok(text) = source.extract::<String>() {
+        if !text.contains('\n') && Path::new(&text).is_file() {
+            Ok(fs::read_to_string(text)?)
+        } else {
+            Ok(text)
+        }
+    } else {
+        let path = source.extract::<PathBuf>()?;
+        Ok(fs::read_to_string(path)?)
+    }
 }
 
 impl Table {
@@ -363,29 +813,310 @@ impl Table {
         v.append(&mut cell);
         Self {
             table: v,
+            columns: vec![],
             border: Border::Default,
+            column_separator: false,
+            max_width: None,
+            shrink: Shrink::Proportional,
+            fit_terminal: false,
+            markdown_merge: MarkdownMerge::Blank,
         }
     }
 
     pub fn new(table: Vec<Vec<Cell>>) -> Table {
         Self {
             table,
+            columns: vec![],
             border: Border::Default,
+            column_separator: false,
+            max_width: None,
+            shrink: Shrink::Proportional,
+            fit_terminal: false,
+            markdown_merge: MarkdownMerge::Blank,
         }
     }
 
-    pub fn to_native(&self) -> table::Table {
-        table::Table::new(
-            self.table
-                .iter()
-                .map(|row| row.iter().map(|cell| cell.to_native()).collect_vec())
-                .collect_vec(),
+    /// Create a new table from csv text. Short records are filled with empty cells.
+    pub fn native_from_csv(
+        text: &str,
+        has_header: bool,
+        delimiter: char,
+        splitter: bool,
+    ) -> Result<Table, FormatterError> {
+        let records = load::csv::parse(text, delimiter)?;
+        let w = records.iter().map(|r| r.len()).max().unwrap_or(0);
+        let mut rows = records
+            .into_iter()
+            .map(|r| {
+                let len = r.len();
+                r.into_iter()
+                    .map(|s| Cell::default().native_with_content(Content::text(s)))
+                    .chain(std::iter::repeat_n(
+                        Cell::default().native_with_content(Content::Empty),
+                        w - len,
+                    ))
+                    .collect_vec()
+            })
+            .collect_vec();
+        if has_header && !rows.is_empty() {
+            let header = rows.remove(0);
+            Ok(Table::native_create(header, rows, splitter))
+        } else {
+            Ok(Table::new(rows))
+        }
+    }
+
+    pub fn repr(&self) -> String {
+        format!(
+            "<table.Table(border={}, size=({}))>",
+            self.border.repr(),
+            if self.table.is_empty() {
+                "0x0".to_string()
+            } else {
+                let h = self.table.len();
+                let w = self.table[0].len();
+                format!("{}x{}", w, h)
+            }
         )
-        .with_border(self.border.to_native())
     }
 
+    pub fn native_with_border(mut self, border: Border) -> Table {
+        self.border = border;
+        self
+    }
+    pub fn native_set_border(&mut self, border: Border) {
+        self.border = border;
+    }
+    pub fn native_with_columns(mut self, columns: Vec<Column>) -> Table {
+        self.columns = columns;
+        self
+    }
+    pub fn native_set_columns(&mut self, columns: Vec<Column>) {
+        self.columns = columns;
+    }
+    pub fn native_with_column_separator(mut self, column_separator: bool) -> Table {
+        self.column_separator = column_separator;
+        self
+    }
+    pub fn native_set_column_separator(&mut self, column_separator: bool) {
+        self.column_separator = column_separator;
+    }
+    pub fn native_with_max_width(mut self, max_width: Option<usize>) -> Table {
+        self.max_width = max_width;
+        self
+    }
+    pub fn native_set_max_width(&mut self, max_width: Option<usize>) {
+        self.max_width = max_width;
+    }
+    pub fn native_with_shrink(mut self, shrink: Shrink) -> Table {
+        self.shrink = shrink;
+        self
+    }
+    pub fn native_set_shrink(&mut self, shrink: Shrink) {
+        self.shrink = shrink;
+    }
+    pub fn native_with_fit_terminal(mut self, fit_terminal: bool) -> Table {
+        self.fit_terminal = fit_terminal;
+        self
+    }
+    pub fn native_set_fit_terminal(&mut self, fit_terminal: bool) {
+        self.fit_terminal = fit_terminal;
+    }
+    pub fn native_with_markdown_merge(mut self, markdown_merge: MarkdownMerge) -> Table {
+        self.markdown_merge = markdown_merge;
+        self
+    }
+    pub fn native_set_markdown_merge(&mut self, markdown_merge: MarkdownMerge) {
+        self.markdown_merge = markdown_merge;
+    }
+
+    /// This function will overwrite the `overflow` property of every cells in the table.
+    pub fn native_overwrite_overflow(&mut self, overflow: Overflow) {
+        for row in self.table.iter_mut() {
+            for cell in row.iter_mut() {
+                cell.native_set_overflow(overflow)
+            }
+        }
+    }
+
+    /// The table with the settings of `columns` applied to its cells, and the header row of the columns added.
+    /// This is what the renderers and `to_csv` see.
+    pub fn native_resolve_columns(&self) -> Result<Cow<'_, Table>, TableError> {
+        if self.columns.is_empty() {
+            return Ok(Cow::Borrowed(self));
+        }
+        let w = match render::validate(self)? {
+            0 if self.table.is_empty() => self.columns.len(),
+            w => w,
+        };
+        let mut table = Vec::with_capacity(self.table.len() + 2);
+        if self.columns.iter().any(|c| c.header.is_some()) {
+            let header = (0..w)
+                .map(|x| {
+                    let text = self.columns.get(x).and_then(|c| c.header.clone()).unwrap_or_default();
+                    Cell::default()
+                        .native_with_content(Content::text(text))
+                        .native_with_formatter(vec![Formatter::Bold])
+                })
+                .collect_vec();
+            let splitter = (0..w)
+                .map(|_| {
+                    Cell::default()
+                        .native_with_content(Content::Splitter)
+                        .native_with_formatter(vec![Formatter::Bold])
+                })
+                .collect_vec();
+            table.push(header);
+            table.push(splitter);
+        }
+        table.extend(self.table.iter().cloned());
+        let table = table
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .enumerate()
+                    .map(|(x, cell)| match self.columns.get(x) {
+                        Some(column) => column.apply(cell),
+                        None => cell,
+                    })
+                    .collect_vec()
+            })
+            .collect_vec();
+        Ok(Cow::Owned(Table {
+            table,
+            columns: vec![],
+            border: self.border,
+            column_separator: self.column_separator,
+            max_width: self.max_width,
+            shrink: self.shrink.clone(),
+            fit_terminal: self.fit_terminal,
+            markdown_merge: self.markdown_merge,
+        }))
+    }
+
+    /// This will render the table according to the render settings.
+    pub fn native_rendered_by(
+        &self,
+        setting: Renderer,
+        writer: &mut impl io::Write,
+    ) -> Result<(), TableError> {
+        let table = self.native_resolve_columns()?;
+        match setting {
+            Renderer::Normal => render::text::render(&table, writer, &self.columns, Paint::Terminal),
+            Renderer::Raw => render::text::render(&table, writer, &self.columns, Paint::None),
+            Renderer::Markdown => render::markdown::render(&table, writer, &self.columns),
+            Renderer::Html => render::html::render(&table, writer),
+            Renderer::Latex => render::latex::render(&table, writer),
+            Renderer::RstGrid => render::rst::render_grid(&table, writer),
+            Renderer::RstSimple => render::rst::render_simple(&table, writer),
+            Renderer::AsciiDoc => render::asciidoc::render(&table, writer),
+            Renderer::Org => render::org::render(&table, writer),
+            Renderer::MediaWiki => render::mediawiki::render(&table, writer),
+            Renderer::JiraWiki => render::jira::render(&table, writer),
+        }
+    }
+
+    /// Export the table as csv, see `Table.to_csv`.
+    pub fn native_to_csv(
+        &self,
+        writer: &mut impl io::Write,
+        delimiter: char,
+        quote_style: QuoteStyle,
+        fill_merge: bool,
+    ) -> Result<(), TableError> {
+        let table = self.native_resolve_columns()?;
+        render::csv::render(&table, writer, delimiter, quote_style, fill_merge)
+    }
+
+    /// Render the table in a html `<pre>` block, see `Table.render_html_pre`.
+    pub fn native_render_html_pre(&self, writer: &mut impl io::Write) -> Result<(), TableError> {
+        let table = self.native_resolve_columns()?;
+        render::html::render_pre(&table, writer, &self.columns)
+    }
+
+    /// Render the table as SVG, see `Table.to_svg`.
+    pub fn native_to_svg(
+        &self,
+        writer: &mut impl io::Write,
+        font_family: &str,
+        font_size: f64,
+    ) -> Result<(), TableError> {
+        let table = self.native_resolve_columns()?;
+        render::svg::render(&table, writer, &self.columns, font_family, font_size)
+    }
+
+    /// Export the table as json, see `Table.to_json`.
+    pub fn native_to_json(&self, writer: &mut impl io::Write, pretty: bool) -> Result<(), TableError> {
+        render::json::render(self, writer, pretty)
+    }
+
+    /// Create a table from json exported by `Table.to_json`.
+    pub fn native_from_json(text: &str) -> Result<Table, FormatterError> {
+        load::json::parse(text)
+    }
+
+    pub fn get_table(&self) -> &[Vec<Cell>] {
+        &self.table
+    }
+    pub fn get_border(&self) -> Border {
+        self.border
+    }
+    pub fn get_columns(&self) -> &[Column] {
+        &self.columns
+    }
+    pub fn get_column_separator(&self) -> bool {
+        self.column_separator
+    }
+    pub fn get_max_width(&self) -> Option<usize> {
+        self.max_width
+    }
+    pub fn get_shrink(&self) -> &Shrink {
+        &self.shrink
+    }
+    pub fn get_fit_terminal(&self) -> bool {
+        self.fit_terminal
+    }
+    pub fn get_markdown_merge(&self) -> MarkdownMerge {
+        self.markdown_merge
+    }
+
+    #[cfg(feature = "python")]
     fn regist_self(_py: Python, m: &PyModule) -> PyResult<()> {
         m.add_class::<Table>()?;
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn column_applies_to_unset_fields() {
+        let column = Column::default()
+            .native_with_align(Some(Align::Right))
+            .native_with_overflow(Some(Overflow::Wrap))
+            .native_with_padding(Some(Padding::new(1, 1)));
+        let cell = column.apply(Cell::default().native_with_content(Content::text("a".to_string())));
+        assert_eq!(cell.get_align(), Align::Right);
+        assert_eq!(cell.get_overflow(), Overflow::Wrap);
+        assert_eq!(cell.get_padding(), Padding::new(1, 1));
+    }
+
+    #[test]
+    fn cell_overrides_column_with_defaults() {
+        let column = Column::default()
+            .native_with_align(Some(Align::Right))
+            .native_with_overflow(Some(Overflow::Wrap))
+            .native_with_padding(Some(Padding::new(1, 1)));
+        let cell = Cell::default()
+            .native_with_content(Content::text("a".to_string()))
+            .native_with_align(Align::Left)
+            .native_with_overflow(Overflow::Ellipse)
+            .native_with_padding(Padding::Empty);
+        let cell = column.apply(cell);
+        assert_eq!(cell.get_explicit_align(), Some(Align::Left));
+        assert_eq!(cell.get_explicit_overflow(), Some(Overflow::Ellipse));
+        assert_eq!(cell.get_explicit_padding(), Some(Padding::Empty));
+    }
+}
@@ -1,11 +1,26 @@
+from typing import List
+
 class Align:
     Left: Align
     Right: Align
     Center: Align
 
+class VAlign:
+    Top: VAlign
+    Middle: VAlign
+    Bottom: VAlign
+
 class Overflow:
     Hide: Overflow
     Ellipse: Overflow
+    Wrap: Overflow
+    WrapChar: Overflow
+
+class Shrink:
+    Proportional: Shrink
+    Widest: Shrink
+    @staticmethod
+    def priority(priority: List[int], min_width: List[int] = []) -> Shrink: ...
 
 class Padding:
     Empty: Padding
@@ -60,13 +75,67 @@ class Border:
     Horizontal: Border
     Vertical: Border
     All: Border
-    def __init__(self, left: bool, right: bool, top: bool, bottom: bool) -> Border:
+    def __init__(self, left: bool, right: bool, top: bool, bottom: bool, style: BorderStyle = BorderStyle.Default) -> Border:
         self.left: bool
         self.right: bool
         self.top: bool
         self.bottom: bool
+        self.style: BorderStyle
+    def with_style(self, style: BorderStyle) -> Border: ...
+
+class BorderStyle:
+    Default: BorderStyle
+    Ascii: BorderStyle
+    Single: BorderStyle
+    Double: BorderStyle
+    Rounded: BorderStyle
+    Heavy: BorderStyle
+    def __init__(
+        self,
+        horizontal: str,
+        vertical: str,
+        top_left: str,
+        top_right: str,
+        bottom_left: str,
+        bottom_right: str,
+        top_junction: str,
+        bottom_junction: str,
+        left_junction: str,
+        right_junction: str,
+        cross: str,
+    ) -> BorderStyle:
+        self.horizontal: str
+        self.vertical: str
+        self.top_left: str
+        self.top_right: str
+        self.bottom_left: str
+        self.bottom_right: str
+        self.top_junction: str
+        self.bottom_junction: str
+        self.left_junction: str
+        self.right_junction: str
+        self.cross: str
 
 class Renderer:
     Normal: Renderer
     Raw: Renderer
     Markdown: Renderer
+    Html: Renderer
+    Latex: Renderer
+    RstGrid: Renderer
+    RstSimple: Renderer
+    AsciiDoc: Renderer
+    Org: Renderer
+    MediaWiki: Renderer
+    JiraWiki: Renderer
+
+class QuoteStyle:
+    Necessary: QuoteStyle
+    Always: QuoteStyle
+    NonNumeric: QuoteStyle
+    Never: QuoteStyle
+
+class MarkdownMerge:
+    Blank: MarkdownMerge
+    Repeat: MarkdownMerge
+    Html: MarkdownMerge
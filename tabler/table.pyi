@@ -1,5 +1,6 @@
 from . import settings
-from typing import Optional, List
+from typing import IO, List, Optional, Union
+from os import PathLike
 
 class Content:
     Empty: Content
@@ -9,30 +10,95 @@ class Content:
 class Cell:
     def __init__(self, c: Content) -> Cell:
         self.content: Content
-        self.overflow: settings.Overflow
+        self.overflow: Optional[settings.Overflow]
         self.width: Optional[int]
-        self.align: settings.Align
-        self.padding: settings.Padding
+        self.align: Optional[settings.Align]
+        self.valign: settings.VAlign
+        self.padding: Optional[settings.Padding]
         self.merge: Optional[int]
+        self.row_span: Optional[int]
         self.formatter: List[settings.Formatter]
     
     def with_content(self, content: Content) -> Cell: ...
     def with_overflow(self, overflow: settings.Overflow) -> Cell: ...
     def with_width(self, width: Optional[int]) -> Cell: ...
     def with_align(self, align: settings.Align) -> Cell: ...
+    def with_valign(self, valign: settings.VAlign) -> Cell: ...
     def with_padding(self, padding: settings.Padding) -> Cell: ...
     def with_merge(self, merge: Optional[int]) -> Cell: ...
+    def with_row_span(self, row_span: Optional[int]) -> Cell: ...
     def with_formatter(self, formatter: List[settings.Formatter]) -> Cell: ...
     def append_formatter(self, formatter: List[settings.Formatter]): ...
     def with_appended_formatter(self, formatter: List[settings.Formatter]) -> Cell: ...
 
 
+class Column:
+    def __init__(
+        self,
+        align: Optional[settings.Align] = None,
+        width: Optional[int] = None,
+        min_width: Optional[int] = None,
+        max_width: Optional[int] = None,
+        overflow: Optional[settings.Overflow] = None,
+        padding: Optional[settings.Padding] = None,
+        formatter: List[settings.Formatter] = [],
+        header: Optional[str] = None,
+    ) -> Column:
+        self.align: Optional[settings.Align]
+        self.width: Optional[int]
+        self.min_width: Optional[int]
+        self.max_width: Optional[int]
+        self.overflow: Optional[settings.Overflow]
+        self.padding: Optional[settings.Padding]
+        self.formatter: List[settings.Formatter]
+        self.header: Optional[str]
+
+    def with_align(self, align: Optional[settings.Align]) -> Column: ...
+    def with_width(self, width: Optional[int]) -> Column: ...
+    def with_min_width(self, min_width: Optional[int]) -> Column: ...
+    def with_max_width(self, max_width: Optional[int]) -> Column: ...
+    def with_overflow(self, overflow: Optional[settings.Overflow]) -> Column: ...
+    def with_padding(self, padding: Optional[settings.Padding]) -> Column: ...
+    def with_formatter(self, formatter: List[settings.Formatter]) -> Column: ...
+    def with_header(self, header: Optional[str]) -> Column: ...
+
+
 class Table:
     def __init__(self, data: List[List[Cell]]) -> Table:
         self.table: List[List[Cell]]
+        self.columns: List[Column]
         self.border: settings.Border
+        self.column_separator: bool
+        self.max_width: Optional[int]
+        self.shrink: settings.Shrink
+        self.fit_terminal: bool
+        self.markdown_merge: settings.MarkdownMerge
     @staticmethod
     def create(header: List[Cell], content: List[List[Cell]], splitter: bool) -> Table: ...
+    @staticmethod
+    def from_csv(
+        source: Union[str, PathLike, IO[str], IO[bytes]],
+        has_header: bool = True,
+        delimiter: str = ",",
+        splitter: bool = True,
+    ) -> Table: ...
+    @staticmethod
+    def from_json(source: Union[str, PathLike, IO[str], IO[bytes]]) -> Table: ...
 
     def with_border(self, border: settings.Border) -> Table: ...
-    def render(self, setting: settings.Renderer) -> str: ...
\ No newline at end of file
+    def with_columns(self, columns: List[Column]) -> Table: ...
+    def with_column_separator(self, column_separator: bool) -> Table: ...
+    def with_max_width(self, max_width: Optional[int]) -> Table: ...
+    def with_shrink(self, shrink: settings.Shrink) -> Table: ...
+    def with_fit_terminal(self, fit_terminal: bool) -> Table: ...
+    def with_markdown_merge(self, markdown_merge: settings.MarkdownMerge) -> Table: ...
+    def render(self, setting: settings.Renderer) -> str: ...
+    def render_html_pre(self) -> str: ...
+    def to_csv(
+        self,
+        delimiter: str = ",",
+        quote_style: settings.QuoteStyle = settings.QuoteStyle.Necessary,
+        fill_merge: bool = False,
+    ) -> str: ...
+    def to_json(self, pretty: bool = False) -> str: ...
+    def to_svg(self, font_family: str = "monospace", font_size: float = 14.0) -> str: ...
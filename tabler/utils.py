@@ -6,7 +6,7 @@ def from_plaintext(text: Iterable[Iterable[str]]) -> tabler.table.Table:
     """
     Create a table from some plain text.
     """
-    tabler.table.Table(
+    return tabler.table.Table(
         list(
             map(
                 lambda row: list(
@@ -24,7 +24,7 @@ def create_from_plaintext(
     """
     Create a table from header and content.
     """
-    tabler.table.Table.create(
+    return tabler.table.Table.create(
         header=list(
             map(lambda cell: tabler.table.Cell(tabler.table.Content(cell)), header)
         ),